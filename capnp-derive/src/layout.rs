@@ -0,0 +1,52 @@
+/// Places the data fields of a struct the way the capnp compiler does: in ordinal order, each in
+/// the first free slot of its size left over by earlier fields, otherwise at the start of a new
+/// word. A derived struct thereby has the same layout as the struct compiled from its schema.
+#[derive(Default)]
+pub struct DataLayout {
+    words: u32,
+    /// `holes[lg_size]` is the offset of a free slot of `1 << lg_size` bits, 0 if there is none.
+    /// Slot 0 of the first word always holds the first field, so it is never a hole.
+    holes: [u32; 6],
+}
+
+impl DataLayout {
+    /// Allocates a field of `1 << lg_size` bits and returns its offset in units of that size.
+    pub fn allocate(&mut self, lg_size: usize) -> u32 {
+        if let Some(offset) = self.take_hole(lg_size) {
+            return offset;
+        }
+        let offset = self.words << (6 - lg_size);
+        self.words += 1;
+        self.add_holes_at_end(lg_size, offset + 1);
+        offset
+    }
+
+    /// Words in the data section.
+    pub fn words(&self) -> u32 {
+        self.words
+    }
+
+    /// Takes a hole of `lg_size`, splitting a larger one if needed.
+    fn take_hole(&mut self, lg_size: usize) -> Option<u32> {
+        if lg_size >= self.holes.len() {
+            return None;
+        }
+        if self.holes[lg_size] != 0 {
+            let offset = self.holes[lg_size];
+            self.holes[lg_size] = 0;
+            return Some(offset);
+        }
+        let offset = self.take_hole(lg_size + 1)? * 2;
+        self.holes[lg_size] = offset + 1;
+        Some(offset)
+    }
+
+    /// Records the rest of a new word, after a field of `lg_size` took its first slot, as holes.
+    fn add_holes_at_end(&mut self, mut lg_size: usize, mut offset: u32) {
+        while lg_size < self.holes.len() {
+            self.holes[lg_size] = offset;
+            lg_size += 1;
+            offset = (offset + 1) / 2;
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! `#[derive(CapnpSchema)]` declares a Cap'n Proto struct in Rust.
+//!
+//! ```ignore
+//! #[derive(CapnpSchema)]
+//! #[capnp(id = 0xd7f46c866337c03c, name = "Person")]
+//! struct PersonRecord {
+//!     #[capnp(ordinal = 0)]
+//!     firstname: String,
+//!     #[capnp(ordinal = 1)]
+//!     lastname: String,
+//!     /// Public key allowed to update, close or hand off the account, 32 bytes
+//!     #[capnp(ordinal = 2)]
+//!     authority: Vec<u8>,
+//! }
+//! ```
+//!
+//! implements `capnp_models::CapnpSchema` for the struct: the `.capnp` text of the schema, the
+//! layout the capnp compiler would give it and conversions to and from capnp messages.
+//!
+//! Layout follows the ordinals alone, so fields may be reordered or renamed in Rust without
+//! changing the wire format. As in a `.capnp` file, ordinals run from 0 without gaps and a new
+//! field only ever gets the next one. `id` is the schema file ID; without it one is derived from
+//! the struct name. `name` renames the capnp struct, field names are converted to camelCase.
+//!
+//! Fields may be `bool`, the integer types up to 64 bits, `f32`, `f64`, `String` (`Text`) and
+//! `Vec<u8>` (`Data`). Nested structs, lists and unions still need a `.capnp` file.
+
+mod layout;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Ident, Lit,
+    Meta, NestedMeta, PathArguments, Result, Type,
+};
+
+use crate::layout::DataLayout;
+
+#[proc_macro_derive(CapnpSchema, attributes(capnp))]
+pub fn derive_capnp_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// How a field is stored.
+enum Kind {
+    Bool,
+    /// A number in the data section, `1 << lg_size` bits wide.
+    Primitive {
+        capnp_type: &'static str,
+        lg_size: usize,
+    },
+    Text,
+    Data,
+}
+
+impl Kind {
+    fn of(ty: &Type) -> Option<Kind> {
+        let primitive = |capnp_type, lg_size| {
+            Some(Kind::Primitive {
+                capnp_type,
+                lg_size,
+            })
+        };
+        let path = match ty {
+            Type::Path(path) if path.qself.is_none() => &path.path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        match segment.ident.to_string().as_str() {
+            "bool" => Some(Kind::Bool),
+            "i8" => primitive("Int8", 3),
+            "i16" => primitive("Int16", 4),
+            "i32" => primitive("Int32", 5),
+            "i64" => primitive("Int64", 6),
+            "u8" => primitive("UInt8", 3),
+            "u16" => primitive("UInt16", 4),
+            "u32" => primitive("UInt32", 5),
+            "u64" => primitive("UInt64", 6),
+            "f32" => primitive("Float32", 5),
+            "f64" => primitive("Float64", 6),
+            "String" => Some(Kind::Text),
+            "Vec" if is_u8_argument(&segment.arguments) => Some(Kind::Data),
+            _ => None,
+        }
+    }
+
+    fn capnp_type(&self) -> &'static str {
+        match self {
+            Kind::Bool => "Bool",
+            Kind::Primitive { capnp_type, .. } => capnp_type,
+            Kind::Text => "Text",
+            Kind::Data => "Data",
+        }
+    }
+}
+
+fn is_u8_argument(arguments: &PathArguments) -> bool {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => matches!(
+            &arguments.args[0],
+            GenericArgument::Type(Type::Path(path)) if path.path.is_ident("u8")
+        ),
+        _ => false,
+    }
+}
+
+struct Field {
+    ident: Ident,
+    ty: Type,
+    kind: Kind,
+    ordinal: u32,
+    name: String,
+    docs: Vec<String>,
+}
+
+/// `#[capnp(...)]` settings of the struct.
+#[derive(Default)]
+struct StructSettings {
+    id: Option<u64>,
+    name: Option<String>,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "CapnpSchema can't be derived for generic structs",
+        ));
+    }
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "CapnpSchema needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "CapnpSchema can only be derived for structs",
+            ))
+        }
+    };
+
+    let settings = struct_settings(&input.attrs)?;
+    let struct_name = settings.name.unwrap_or_else(|| ident.to_string());
+    let file_id = settings.id.unwrap_or_else(|| default_file_id(&struct_name));
+    if file_id & (1 << 63) == 0 {
+        return Err(Error::new_spanned(
+            ident,
+            "capnp IDs have their highest bit set, generate one with `capnp id`",
+        ));
+    }
+
+    let mut fields = named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().expect("named field");
+            let kind = Kind::of(&field.ty).ok_or_else(|| {
+                Error::new_spanned(
+                    &field.ty,
+                    "unsupported CapnpSchema field type, expected bool, an integer, f32, f64, \
+                     String or Vec<u8>",
+                )
+            })?;
+            let (ordinal, name) = field_settings(&field.attrs, &ident)?;
+            Ok(Field {
+                name: name.unwrap_or_else(|| camel_case(&ident.to_string())),
+                ident,
+                ty: field.ty.clone(),
+                kind,
+                ordinal,
+                docs: docs(&field.attrs),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    fields.sort_by_key(|field| field.ordinal);
+    for (expected, field) in (0..).zip(&fields) {
+        if field.ordinal != expected {
+            let message = if field.ordinal < expected {
+                format!("ordinal @{} is used twice", field.ordinal)
+            } else {
+                format!(
+                    "ordinal @{} is missing, ordinals run from @0 without gaps",
+                    expected
+                )
+            };
+            return Err(Error::new_spanned(&field.ident, message));
+        }
+    }
+
+    let schema = schema_text(file_id, &struct_name, &docs(&input.attrs), &fields);
+
+    let mut data = DataLayout::default();
+    let mut pointers = 0u32;
+    let mut writes = Vec::new();
+    let mut reads = Vec::new();
+    for field in &fields {
+        let (field_ident, ty) = (&field.ident, &field.ty);
+        match field.kind {
+            Kind::Bool => {
+                let offset = Literal::u32_unsuffixed(data.allocate(0));
+                writes.push(quote! { builder.set_bool_field(#offset, self.#field_ident); });
+                reads.push(quote! { #field_ident: reader.get_bool_field(#offset) });
+            }
+            Kind::Primitive { lg_size, .. } => {
+                let offset = Literal::u32_unsuffixed(data.allocate(lg_size));
+                writes.push(quote! {
+                    builder.set_data_field::<#ty>(#offset, self.#field_ident);
+                });
+                reads.push(quote! { #field_ident: reader.get_data_field::<#ty>(#offset) });
+            }
+            Kind::Text => {
+                let index = Literal::u32_unsuffixed(pointers);
+                pointers += 1;
+                writes.push(quote! {
+                    builder
+                        .reborrow()
+                        .get_pointer_field(#index)
+                        .set_text(self.#field_ident.as_str().into());
+                });
+                reads.push(quote! {
+                    #field_ident: <::capnp::text::Reader<'_> as ::capnp::traits::FromPointerReader<'_>>::get_from_pointer(
+                        &reader.get_pointer_field(#index),
+                        ::core::option::Option::None,
+                    )?
+                    .to_str()?
+                    .to_owned()
+                });
+            }
+            Kind::Data => {
+                let index = Literal::u32_unsuffixed(pointers);
+                pointers += 1;
+                writes.push(quote! {
+                    builder
+                        .reborrow()
+                        .get_pointer_field(#index)
+                        .set_data(&self.#field_ident);
+                });
+                reads.push(quote! {
+                    #field_ident: <::capnp::data::Reader<'_> as ::capnp::traits::FromPointerReader<'_>>::get_from_pointer(
+                        &reader.get_pointer_field(#index),
+                        ::core::option::Option::None,
+                    )?
+                    .to_vec()
+                });
+            }
+        }
+    }
+    let data_words = Literal::u32_unsuffixed(data.words());
+    let pointers = Literal::u32_unsuffixed(pointers);
+
+    Ok(quote! {
+        impl ::capnp_models::CapnpSchema for #ident {
+            const SCHEMA: &'static str = #schema;
+            const STRUCT_NAME: &'static str = #struct_name;
+            const STRUCT_SIZE: ::capnp::private::layout::StructSize =
+                ::capnp::private::layout::StructSize {
+                    data: #data_words,
+                    pointers: #pointers,
+                };
+
+            #[allow(unused_mut, unused_variables)]
+            fn write_capnp(&self, mut builder: ::capnp::private::layout::StructBuilder<'_>) {
+                #(#writes)*
+            }
+
+            #[allow(unused_variables)]
+            fn read_capnp(
+                reader: ::capnp::private::layout::StructReader<'_>,
+            ) -> ::capnp::Result<Self> {
+                ::core::result::Result::Ok(#ident { #(#reads,)* })
+            }
+        }
+    })
+}
+
+/// The `.capnp` file declaring the struct.
+fn schema_text(file_id: u64, struct_name: &str, docs: &[String], fields: &[Field]) -> String {
+    let mut schema = format!("@{:#018x};\n\n", file_id);
+    for line in docs {
+        schema.push_str(&format!("# {}\n", line));
+    }
+    schema.push_str(&format!("struct {} {{\n", struct_name));
+    for field in fields {
+        for line in &field.docs {
+            schema.push_str(&format!("  # {}\n", line));
+        }
+        schema.push_str(&format!(
+            "  {} @{} :{};\n",
+            field.name,
+            field.ordinal,
+            field.kind.capnp_type()
+        ));
+    }
+    schema.push_str("}\n");
+    schema
+}
+
+fn struct_settings(attrs: &[Attribute]) -> Result<StructSettings> {
+    let mut settings = StructSettings::default();
+    for (path, lit) in capnp_settings(attrs)? {
+        if path.is_ident("id") {
+            settings.id = Some(int_setting(&lit)?);
+        } else if path.is_ident("name") {
+            settings.name = Some(str_setting(&lit)?);
+        } else {
+            return Err(Error::new_spanned(
+                path,
+                "unknown capnp setting, expected `id` or `name`",
+            ));
+        }
+    }
+    Ok(settings)
+}
+
+/// The ordinal and capnp name of a field.
+fn field_settings(attrs: &[Attribute], ident: &Ident) -> Result<(u32, Option<String>)> {
+    let (mut ordinal, mut name) = (None, None);
+    for (path, lit) in capnp_settings(attrs)? {
+        if path.is_ident("ordinal") {
+            ordinal = Some(int_setting(&lit)?);
+        } else if path.is_ident("name") {
+            name = Some(str_setting(&lit)?);
+        } else {
+            return Err(Error::new_spanned(
+                path,
+                "unknown capnp setting, expected `ordinal` or `name`",
+            ));
+        }
+    }
+    let ordinal = ordinal
+        .ok_or_else(|| Error::new_spanned(ident, "missing `#[capnp(ordinal = N)]` on field"))?;
+    Ok((ordinal, name))
+}
+
+/// The `key = value` pairs of the `#[capnp(...)]` attributes in `attrs`.
+fn capnp_settings(attrs: &[Attribute]) -> Result<Vec<(syn::Path, Lit)>> {
+    let mut settings = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("capnp")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected `#[capnp(key = value, ...)]`",
+                ))
+            }
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(pair)) => settings.push((pair.path, pair.lit)),
+                nested => {
+                    return Err(Error::new_spanned(nested, "expected `key = value`"));
+                }
+            }
+        }
+    }
+    Ok(settings)
+}
+
+fn int_setting<N>(lit: &Lit) -> Result<N>
+where
+    N: std::str::FromStr,
+    N::Err: std::fmt::Display,
+{
+    match lit {
+        Lit::Int(int) => int.base10_parse(),
+        _ => Err(Error::new_spanned(lit, "expected an integer")),
+    }
+}
+
+fn str_setting(lit: &Lit) -> Result<String> {
+    match lit {
+        Lit::Str(str) => Ok(str.value()),
+        _ => Err(Error::new_spanned(lit, "expected a string")),
+    }
+}
+
+/// Lines of the doc comments in `attrs`, which become comments in the schema.
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(pair)) => match pair.lit {
+                Lit::Str(str) => Some(str.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// `birth_year` to `birthYear`.
+fn camel_case(snake_case: &str) -> String {
+    let mut camel_case = String::with_capacity(snake_case.len());
+    let mut upper = false;
+    for c in snake_case.trim_start_matches("r#").chars() {
+        if c == '_' {
+            upper = !camel_case.is_empty();
+        } else if upper {
+            camel_case.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel_case.push(c);
+        }
+    }
+    camel_case
+}
+
+/// FNV-1a of the struct name with the high bit set, so a schema keeps its ID across builds.
+fn default_file_id(struct_name: &str) -> u64 {
+    let hash = struct_name
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    hash | (1 << 63)
+}
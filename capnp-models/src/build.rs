@@ -1,8 +1,34 @@
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use capnp::message::{Builder, ReaderOptions};
+use capnp::serialize;
+use capnpc::schema_capnp::code_generator_request;
 use capnpc::CompilerCommand;
 
+/// Schemas descriptors publish, and the file in `OUT_DIR` their binary `CodeGeneratorRequest`
+/// goes to.
+const PUBLISHED_SCHEMAS: [(&str, &str); 2] = [
+    ("./src/person.capnp", "person_capnp.bin"),
+    ("./src/person_v2.capnp", "person_v2_capnp.bin"),
+];
+
 fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR"));
+
+    for (schema, request) in PUBLISHED_SCHEMAS {
+        let raw_request = out_dir.join(request).with_extension("raw");
+        CompilerCommand::new()
+            .file(schema)
+            .output_path("./")
+            .raw_code_generator_request_path(&raw_request)
+            .run()
+            .expect("compiling schema");
+        publish_request(&raw_request, &out_dir.join(request));
+    }
+
     CompilerCommand::new()
-        .file("./src/person.capnp")
+        .file("./src/instruction.capnp")
         .output_path("./")
         .run()
         .expect("compiling schema");
@@ -12,4 +38,55 @@ fn main() {
         .output_path("./")
         .run()
         .expect("compiling schema");
-}
\ No newline at end of file
+}
+
+/// Writes the request at `raw_request` to `published` with only what readers of the schema need:
+/// the nodes of the compiled file, without those of its imports, source info or compiler
+/// version. The payload stays small enough to store on-chain and depends on the schema rather
+/// than on where and with which capnp release it was built.
+fn publish_request(raw_request: &Path, published: &Path) {
+    let raw = fs::read(raw_request).expect("reading CodeGeneratorRequest");
+    let message = serialize::read_message(raw.as_slice(), ReaderOptions::new())
+        .expect("reading CodeGeneratorRequest");
+    let request = message
+        .get_root::<code_generator_request::Reader>()
+        .expect("reading CodeGeneratorRequest");
+    let requested_file = request.get_requested_files().unwrap().get(0);
+    let file_id = requested_file.get_id();
+
+    let nodes = request.get_nodes().unwrap();
+    let file_name = nodes
+        .iter()
+        .find(|node| node.get_id() == file_id)
+        .expect("file node")
+        .get_display_name()
+        .unwrap()
+        .to_string()
+        .unwrap();
+    let scope = format!("{}:", file_name);
+    let kept: Vec<_> = nodes
+        .iter()
+        .filter(|node| {
+            let display_name = node.get_display_name().unwrap().to_str().unwrap();
+            node.get_id() == file_id || display_name.starts_with(&scope)
+        })
+        .collect();
+
+    let mut builder = Builder::new_default();
+    let mut published_request = builder.init_root::<code_generator_request::Builder>();
+    let mut published_nodes = published_request.reborrow().init_nodes(kept.len() as u32);
+    for (index, node) in kept.into_iter().enumerate() {
+        published_nodes
+            .set_with_caveats(index as u32, node)
+            .unwrap();
+    }
+    // Named without its directory, so code generated from the request is named after the schema.
+    let base_name = Path::new(&file_name).file_name().unwrap().to_str().unwrap();
+    let mut published_file = published_request.init_requested_files(1).get(0);
+    published_file.set_id(file_id);
+    published_file.set_filename(base_name.into());
+
+    let mut bytes = Vec::new();
+    serialize::write_message(&mut bytes, &builder).unwrap();
+    fs::write(published, bytes).expect("writing CodeGeneratorRequest");
+}
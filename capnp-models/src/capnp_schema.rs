@@ -0,0 +1,102 @@
+//! Structs declared in Rust with `#[derive(CapnpSchema)]` instead of in a `.capnp` file.
+
+use std::marker::PhantomData;
+
+use capnp::message::{Allocator, Builder, HeapAllocator, Reader, ReaderSegments};
+use capnp::private::layout::{
+    PointerBuilder, PointerReader, StructBuilder, StructReader, StructSize,
+};
+use capnp::traits::{FromPointerBuilder, FromPointerReader, IntoInternalStructReader};
+use capnp::Word;
+
+/// A Rust struct stored as a capnp struct. Derive it rather than implementing it by hand, see
+/// the `capnp-derive` crate for the attributes.
+pub trait CapnpSchema: Sized {
+    /// `.capnp` file declaring the struct, to publish in a descriptor.
+    const SCHEMA: &'static str;
+
+    /// Name of the struct in `SCHEMA`.
+    const STRUCT_NAME: &'static str;
+
+    const STRUCT_SIZE: StructSize;
+
+    /// Writes every field into `builder`, a struct of at least `STRUCT_SIZE`.
+    fn write_capnp(&self, builder: StructBuilder<'_>);
+
+    fn read_capnp(reader: StructReader<'_>) -> capnp::Result<Self>;
+
+    /// A new message with `self` at the root.
+    fn to_message(&self) -> Builder<HeapAllocator> {
+        let mut message = Builder::new_default();
+        let root: DerivedBuilder<Self> = message.init_root();
+        self.write_capnp(root.builder);
+        message
+    }
+
+    /// Writes `self` over the root struct of `message`. Fields of the root `Self` doesn't have,
+    /// such as those added by a newer schema, keep their values.
+    fn write_root<A: Allocator>(&self, message: &mut Builder<A>) -> capnp::Result<()> {
+        let root: DerivedBuilder<Self> = message.get_root()?;
+        self.write_capnp(root.builder);
+        Ok(())
+    }
+
+    fn read_root<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self> {
+        Self::from_reader(message.get_root::<DerivedReader<Self>>()?)
+    }
+
+    /// Reads any struct reader, including those generated from a `.capnp` file, e.g. a
+    /// `person_capnp::person::Reader` into a struct deriving the same schema.
+    fn from_reader<'a>(reader: impl IntoInternalStructReader<'a>) -> capnp::Result<Self> {
+        Self::read_capnp(reader.into_internal_struct_reader())
+    }
+}
+
+/// Reader of a `T` in a message, to pass to `get_root` and the like.
+pub struct DerivedReader<'a, T> {
+    reader: StructReader<'a>,
+    struct_type: PhantomData<T>,
+}
+
+impl<'a, T: CapnpSchema> FromPointerReader<'a> for DerivedReader<'a, T> {
+    fn get_from_pointer(
+        reader: &PointerReader<'a>,
+        default: Option<&'a [Word]>,
+    ) -> capnp::Result<Self> {
+        Ok(DerivedReader {
+            reader: reader.get_struct(default)?,
+            struct_type: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> IntoInternalStructReader<'a> for DerivedReader<'a, T> {
+    fn into_internal_struct_reader(self) -> StructReader<'a> {
+        self.reader
+    }
+}
+
+/// Builder of a `T` in a message, to pass to `init_root` and the like.
+pub struct DerivedBuilder<'a, T> {
+    builder: StructBuilder<'a>,
+    struct_type: PhantomData<T>,
+}
+
+impl<'a, T: CapnpSchema> FromPointerBuilder<'a> for DerivedBuilder<'a, T> {
+    fn init_pointer(builder: PointerBuilder<'a>, _size: u32) -> Self {
+        DerivedBuilder {
+            builder: builder.init_struct(T::STRUCT_SIZE),
+            struct_type: PhantomData,
+        }
+    }
+
+    fn get_from_pointer(
+        builder: PointerBuilder<'a>,
+        default: Option<&'a [Word]>,
+    ) -> capnp::Result<Self> {
+        Ok(DerivedBuilder {
+            builder: builder.get_struct(T::STRUCT_SIZE, default)?,
+            struct_type: PhantomData,
+        })
+    }
+}
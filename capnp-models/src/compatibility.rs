@@ -0,0 +1,513 @@
+//! Checks that a new version of a capnp schema reads and writes the same messages as the old one.
+//!
+//! [`check_compatibility`] compares a struct of two `CodeGeneratorRequest`s, and every struct,
+//! group and enum it reaches through its fields, against capnp's evolution rules: fields keep
+//! their ordinals, types, offsets, data defaults and union discriminants, enumerants stay, and the
+//! data and pointer sections only grow. New fields, enumerants and union members are fine, as
+//! are renames. Type changes are reported even where capnp allows them, e.g. `List(Text)` to a
+//! list of structs whose first field is `Text`.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::mem;
+
+use crate::schema_capnp::{code_generator_request, field, node, type_, value};
+
+/// What [`check_compatibility`] found. The new schema can replace the old one if it is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompatibilityReport {
+    pub incompatibilities: Vec<Incompatibility>,
+}
+
+impl CompatibilityReport {
+    pub fn is_compatible(&self) -> bool {
+        self.incompatibilities.is_empty()
+    }
+}
+
+impl fmt::Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_compatible() {
+            return write!(f, "compatible");
+        }
+        write!(
+            f,
+            "incompatible, {} change(s) break existing messages:",
+            self.incompatibilities.len()
+        )?;
+        for incompatibility in &self.incompatibilities {
+            write!(f, "\n  {}", incompatibility)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Incompatibility {
+    /// Field names from the root struct of the old schema, e.g. `Person.address.city`.
+    pub path: String,
+    pub change: Change,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.change)
+    }
+}
+
+/// A change that breaks messages written with the old schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// No field of the new struct has the ordinal, `None` for a removed group.
+    FieldRemoved {
+        ordinal: Option<u16>,
+    },
+    FieldRenumbered {
+        old_ordinal: u16,
+        new_ordinal: u16,
+    },
+    TypeChanged {
+        old: String,
+        new: String,
+    },
+    /// The field is stored elsewhere in its section, in units of its type's size.
+    OffsetChanged {
+        old: u32,
+        new: u32,
+    },
+    /// The default of a data field, which values are stored XORed with.
+    DefaultChanged,
+    /// `None` for fields outside the union.
+    DiscriminantChanged {
+        old: Option<u16>,
+        new: Option<u16>,
+    },
+    /// The union's discriminant is stored elsewhere in the data section, in 16 bit units.
+    DiscriminantOffsetChanged {
+        old: u32,
+        new: u32,
+    },
+    DataSectionShrunk {
+        old_words: u16,
+        new_words: u16,
+    },
+    PointerSectionShrunk {
+        old_pointers: u16,
+        new_pointers: u16,
+    },
+    EnumerantRemoved {
+        ordinal: u16,
+        name: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::FieldRemoved {
+                ordinal: Some(ordinal),
+            } => write!(f, "field @{} removed", ordinal),
+            Change::FieldRemoved { ordinal: None } => write!(f, "group removed"),
+            Change::FieldRenumbered {
+                old_ordinal,
+                new_ordinal,
+            } => write!(f, "renumbered from @{} to @{}", old_ordinal, new_ordinal),
+            Change::TypeChanged { old, new } => write!(f, "type changed from {} to {}", old, new),
+            Change::OffsetChanged { old, new } => {
+                write!(f, "moved from offset {} to {}", old, new)
+            }
+            Change::DefaultChanged => write!(f, "default value changed"),
+            Change::DiscriminantChanged { old: None, new } => {
+                write!(
+                    f,
+                    "moved into a union as member {}",
+                    new.unwrap_or_default()
+                )
+            }
+            Change::DiscriminantChanged { old, new: None } => {
+                write!(
+                    f,
+                    "moved out of a union, was member {}",
+                    old.unwrap_or_default()
+                )
+            }
+            Change::DiscriminantChanged { old, new } => write!(
+                f,
+                "union discriminant changed from {} to {}",
+                old.unwrap_or_default(),
+                new.unwrap_or_default()
+            ),
+            Change::DiscriminantOffsetChanged { old, new } => {
+                write!(f, "union discriminant moved from offset {} to {}", old, new)
+            }
+            Change::DataSectionShrunk {
+                old_words,
+                new_words,
+            } => write!(
+                f,
+                "data section shrank from {} to {} words",
+                old_words, new_words
+            ),
+            Change::PointerSectionShrunk {
+                old_pointers,
+                new_pointers,
+            } => write!(
+                f,
+                "pointer section shrank from {} to {} pointers",
+                old_pointers, new_pointers
+            ),
+            Change::EnumerantRemoved { ordinal, name } => {
+                write!(f, "enumerant {} @{} removed", name, ordinal)
+            }
+        }
+    }
+}
+
+/// ID of the struct `name` in `request`, as it is named in its file, e.g. `Person` or
+/// `Node.Parameter`.
+pub fn find_struct(request: code_generator_request::Reader, name: &str) -> Option<u64> {
+    request
+        .get_nodes()
+        .ok()?
+        .iter()
+        .find(|node| {
+            let is_struct = match node.which() {
+                Ok(node::Struct(struct_node)) => !struct_node.get_is_group(),
+                _ => false,
+            };
+            is_struct && short_name(*node) == Some(name)
+        })
+        .map(|node| node.get_id())
+}
+
+/// Checks that messages whose root is the struct `old_struct` of `old` still read, and are
+/// written the same way, with the struct `new_struct` of `new`. The two may be declared in
+/// different files: structs and enums are paired up through the fields that use them, not by ID.
+pub fn check_compatibility(
+    old: code_generator_request::Reader,
+    old_struct: u64,
+    new: code_generator_request::Reader,
+    new_struct: u64,
+) -> capnp::Result<CompatibilityReport> {
+    let mut checker = Checker {
+        old: Nodes::new(old)?,
+        new: Nodes::new(new)?,
+        checked: HashSet::new(),
+        report: CompatibilityReport::default(),
+    };
+    let root = checker.old.node(old_struct)?;
+    let path = short_name(root).unwrap_or_default().to_string();
+    checker.check_struct(&path, old_struct, new_struct)?;
+    Ok(checker.report)
+}
+
+struct Nodes<'a> {
+    nodes: HashMap<u64, node::Reader<'a>>,
+}
+
+impl<'a> Nodes<'a> {
+    fn new(request: code_generator_request::Reader<'a>) -> capnp::Result<Self> {
+        let nodes = request
+            .get_nodes()?
+            .iter()
+            .map(|node| (node.get_id(), node))
+            .collect();
+        Ok(Nodes { nodes })
+    }
+
+    fn node(&self, id: u64) -> capnp::Result<node::Reader<'a>> {
+        self.nodes
+            .get(&id)
+            .copied()
+            .ok_or_else(|| capnp::Error::failed(format!("no node {:#x} in the schema", id)))
+    }
+
+    fn type_name(&self, field_type: type_::Reader) -> capnp::Result<String> {
+        let named = |id| -> capnp::Result<String> {
+            Ok(short_name(self.node(id)?).unwrap_or_default().to_string())
+        };
+        Ok(match field_type.which()? {
+            type_::Void(()) => "Void".to_string(),
+            type_::Bool(()) => "Bool".to_string(),
+            type_::Int8(()) => "Int8".to_string(),
+            type_::Int16(()) => "Int16".to_string(),
+            type_::Int32(()) => "Int32".to_string(),
+            type_::Int64(()) => "Int64".to_string(),
+            type_::Uint8(()) => "UInt8".to_string(),
+            type_::Uint16(()) => "UInt16".to_string(),
+            type_::Uint32(()) => "UInt32".to_string(),
+            type_::Uint64(()) => "UInt64".to_string(),
+            type_::Float32(()) => "Float32".to_string(),
+            type_::Float64(()) => "Float64".to_string(),
+            type_::Text(()) => "Text".to_string(),
+            type_::Data(()) => "Data".to_string(),
+            type_::List(list) => format!("List({})", self.type_name(list.get_element_type()?)?),
+            type_::Enum(enum_type) => named(enum_type.get_type_id())?,
+            type_::Struct(struct_type) => named(struct_type.get_type_id())?,
+            type_::Interface(interface) => named(interface.get_type_id())?,
+            type_::AnyPointer(_) => "AnyPointer".to_string(),
+        })
+    }
+}
+
+struct Checker<'a> {
+    old: Nodes<'a>,
+    new: Nodes<'a>,
+    /// Pairs of old and new struct and enum IDs already compared, so recursive types end.
+    checked: HashSet<(u64, u64)>,
+    report: CompatibilityReport,
+}
+
+impl<'a> Checker<'a> {
+    fn incompatible(&mut self, path: &str, change: Change) {
+        self.report.incompatibilities.push(Incompatibility {
+            path: path.to_string(),
+            change,
+        });
+    }
+
+    fn check_struct(&mut self, path: &str, old_id: u64, new_id: u64) -> capnp::Result<()> {
+        if !self.checked.insert((old_id, new_id)) {
+            return Ok(());
+        }
+        let (old, new) = match (
+            self.old.node(old_id)?.which()?,
+            self.new.node(new_id)?.which()?,
+        ) {
+            (node::Struct(old), node::Struct(new)) => (old, new),
+            _ => return Err(capnp::Error::failed(format!("{} is not a struct", path))),
+        };
+
+        // Groups share their parent's sections, which the parent checks.
+        if !old.get_is_group() {
+            let (old_words, new_words) = (old.get_data_word_count(), new.get_data_word_count());
+            if new_words < old_words {
+                self.incompatible(
+                    path,
+                    Change::DataSectionShrunk {
+                        old_words,
+                        new_words,
+                    },
+                );
+            }
+            let (old_pointers, new_pointers) = (old.get_pointer_count(), new.get_pointer_count());
+            if new_pointers < old_pointers {
+                self.incompatible(
+                    path,
+                    Change::PointerSectionShrunk {
+                        old_pointers,
+                        new_pointers,
+                    },
+                );
+            }
+        }
+        if old.get_discriminant_count() > 0
+            && new.get_discriminant_count() > 0
+            && old.get_discriminant_offset() != new.get_discriminant_offset()
+        {
+            self.incompatible(
+                path,
+                Change::DiscriminantOffsetChanged {
+                    old: old.get_discriminant_offset(),
+                    new: new.get_discriminant_offset(),
+                },
+            );
+        }
+
+        let new_fields = new.get_fields()?;
+        for old_field in old.get_fields()? {
+            let name = old_field.get_name()?.to_str()?;
+            let field_path = format!("{}.{}", path, name);
+            let ordinal = explicit_ordinal(old_field);
+            let same_name = new_fields
+                .iter()
+                .find(|field| field_name(*field) == Some(name));
+
+            if let (Some(old_ordinal), Some(new_ordinal)) =
+                (ordinal, same_name.and_then(explicit_ordinal))
+            {
+                if old_ordinal != new_ordinal {
+                    self.incompatible(
+                        &field_path,
+                        Change::FieldRenumbered {
+                            old_ordinal,
+                            new_ordinal,
+                        },
+                    );
+                    continue;
+                }
+            }
+            // Slots are matched by ordinal, which allows renames, groups by name.
+            let new_field = match ordinal {
+                Some(_) => new_fields
+                    .iter()
+                    .find(|field| explicit_ordinal(*field) == ordinal),
+                None => same_name,
+            };
+            let new_field = match new_field {
+                Some(new_field) => new_field,
+                None => {
+                    self.incompatible(&field_path, Change::FieldRemoved { ordinal });
+                    continue;
+                }
+            };
+
+            let (old_discriminant, new_discriminant) =
+                (discriminant(old_field), discriminant(new_field));
+            if old_discriminant != new_discriminant {
+                self.incompatible(
+                    &field_path,
+                    Change::DiscriminantChanged {
+                        old: old_discriminant,
+                        new: new_discriminant,
+                    },
+                );
+            }
+
+            match (old_field.which()?, new_field.which()?) {
+                (field::Slot(old_slot), field::Slot(new_slot)) => {
+                    self.check_slot(&field_path, old_slot, new_slot)?
+                }
+                (field::Group(old_group), field::Group(new_group)) => self.check_struct(
+                    &field_path,
+                    old_group.get_type_id(),
+                    new_group.get_type_id(),
+                )?,
+                (old_which, _) => {
+                    let (old, new) = match old_which {
+                        field::Slot(_) => ("a field", "a group"),
+                        field::Group(_) => ("a group", "a field"),
+                    };
+                    self.incompatible(
+                        &field_path,
+                        Change::TypeChanged {
+                            old: old.to_string(),
+                            new: new.to_string(),
+                        },
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_slot(
+        &mut self,
+        path: &str,
+        old: field::slot::Reader,
+        new: field::slot::Reader,
+    ) -> capnp::Result<()> {
+        let (old_type, new_type) = (old.get_type()?, new.get_type()?);
+        if !self.same_type(path, old_type, new_type)? {
+            let change = Change::TypeChanged {
+                old: self.old.type_name(old_type)?,
+                new: self.new.type_name(new_type)?,
+            };
+            self.incompatible(path, change);
+            return Ok(());
+        }
+        if old.get_offset() != new.get_offset() {
+            self.incompatible(
+                path,
+                Change::OffsetChanged {
+                    old: old.get_offset(),
+                    new: new.get_offset(),
+                },
+            );
+        }
+        if data_default(old.get_default_value()?)? != data_default(new.get_default_value()?)? {
+            self.incompatible(path, Change::DefaultChanged);
+        }
+        Ok(())
+    }
+
+    /// Whether values of `old` and `new` are encoded the same way, checking the structs and enums
+    /// they name along the way.
+    fn same_type(
+        &mut self,
+        path: &str,
+        old: type_::Reader,
+        new: type_::Reader,
+    ) -> capnp::Result<bool> {
+        Ok(match (old.which()?, new.which()?) {
+            (type_::List(old), type_::List(new)) => {
+                self.same_type(path, old.get_element_type()?, new.get_element_type()?)?
+            }
+            (type_::Struct(old), type_::Struct(new)) => {
+                self.check_struct(path, old.get_type_id(), new.get_type_id())?;
+                true
+            }
+            (type_::Enum(old), type_::Enum(new)) => {
+                self.check_enum(path, old.get_type_id(), new.get_type_id())?;
+                true
+            }
+            (old, new) => mem::discriminant(&old) == mem::discriminant(&new),
+        })
+    }
+
+    fn check_enum(&mut self, path: &str, old_id: u64, new_id: u64) -> capnp::Result<()> {
+        if !self.checked.insert((old_id, new_id)) {
+            return Ok(());
+        }
+        let (old, new) = match (
+            self.old.node(old_id)?.which()?,
+            self.new.node(new_id)?.which()?,
+        ) {
+            (node::Enum(old), node::Enum(new)) => (old.get_enumerants()?, new.get_enumerants()?),
+            _ => return Err(capnp::Error::failed(format!("{} is not an enum", path))),
+        };
+        for ordinal in new.len()..old.len() {
+            let name = old.get(ordinal).get_name()?.to_str()?.to_string();
+            self.incompatible(
+                path,
+                Change::EnumerantRemoved {
+                    ordinal: ordinal as u16,
+                    name,
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Name of a node in its file, `Outer.Inner` for a node displayed as `file.capnp:Outer.Inner`.
+fn short_name(node: node::Reader) -> Option<&str> {
+    let display_name = node.get_display_name().ok()?.to_str().ok()?;
+    display_name
+        .split_once(':')
+        .map(|(_, short_name)| short_name)
+}
+
+fn field_name(field: field::Reader) -> Option<&str> {
+    field.get_name().ok()?.to_str().ok()
+}
+
+fn explicit_ordinal(field: field::Reader) -> Option<u16> {
+    match field.get_ordinal().which() {
+        Ok(field::ordinal::Explicit(ordinal)) => Some(ordinal),
+        _ => None,
+    }
+}
+
+fn discriminant(field: field::Reader) -> Option<u16> {
+    Some(field.get_discriminant_value()).filter(|value| *value != field::NO_DISCRIMINANT)
+}
+
+/// The bits a data field's values are XORed with, `None` for pointer fields.
+fn data_default(default: value::Reader) -> capnp::Result<Option<u64>> {
+    Ok(Some(match default.which()? {
+        value::Bool(value) => value as u64,
+        value::Int8(value) => value as u64,
+        value::Int16(value) => value as u64,
+        value::Int32(value) => value as u64,
+        value::Int64(value) => value as u64,
+        value::Uint8(value) => value as u64,
+        value::Uint16(value) => value as u64,
+        value::Uint32(value) => value as u64,
+        value::Uint64(value) => value,
+        value::Float32(value) => value.to_bits() as u64,
+        value::Float64(value) => value.to_bits(),
+        value::Enum(value) => value as u64,
+        _ => return Ok(None),
+    }))
+}
@@ -1,2 +1,36 @@
 mod person_capnp;
-mod schema_capnp;
\ No newline at end of file
+mod person_v2_capnp;
+mod instruction_capnp;
+/// The schema of capnp schemas, to read the `CodeGeneratorRequest` the capnp compiler builds.
+pub mod schema_capnp;
+mod capnp_schema;
+pub mod compatibility;
+
+pub use capnp_derive::CapnpSchema;
+pub use capnp_schema::{CapnpSchema, DerivedBuilder, DerivedReader};
+
+/// Every published version of the Person schema, oldest first: version `n` is at index `n - 1`.
+pub const PERSON_SCHEMAS: [&str; 2] = [
+    include_str!("person.capnp"),
+    include_str!("person_v2.capnp"),
+];
+
+/// Gives the bytes it wraps the alignment of a capnp word.
+#[repr(C, align(8))]
+struct WordAligned<T>(T);
+
+/// The word aligned bytes of `file` in the build's output directory.
+macro_rules! include_schema_request {
+    ($file:literal) => {
+        &WordAligned(*include_bytes!(concat!(env!("OUT_DIR"), "/", $file))).0
+    };
+}
+
+/// The binary `CodeGeneratorRequest` of each version in [`PERSON_SCHEMAS`], compiled by the build
+/// and trimmed to the Person file's own nodes. It is what descriptors publish: readers decode it
+/// with `schema_capnp` instead of running the capnp compiler on the text. The bytes are word
+/// aligned, so they can be read in place.
+pub const PERSON_SCHEMA_REQUESTS: [&[u8]; 2] = [
+    include_schema_request!("person_capnp.bin"),
+    include_schema_request!("person_v2_capnp.bin"),
+];
@@ -0,0 +1,54 @@
+//! Where commands read accounts and send transactions.
+
+use solana_client::rpc_client::RpcClient;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+
+use crate::Error;
+
+/// A cluster running the demo program. The CLI talks to one over RPC; tests implement this on
+/// top of `solana-program-test`.
+pub trait Cluster {
+    /// The account at `address`, `None` if there is none.
+    fn account(&mut self, address: &Pubkey) -> Result<Option<Account>, Error>;
+
+    /// Sends `instruction` in a transaction signed and paid for by `signer`, and waits for it
+    /// to be confirmed.
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<Signature, Error>;
+}
+
+/// A cluster behind a JSON RPC endpoint, such as a local validator.
+pub struct RpcCluster {
+    client: RpcClient,
+}
+
+impl RpcCluster {
+    pub fn new(url: String, commitment: CommitmentConfig) -> Self {
+        RpcCluster {
+            client: RpcClient::new_with_commitment(url, commitment),
+        }
+    }
+}
+
+impl Cluster for RpcCluster {
+    fn account(&mut self, address: &Pubkey) -> Result<Option<Account>, Error> {
+        Ok(self
+            .client
+            .get_account_with_commitment(address, self.client.commitment())?
+            .value)
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<Signature, Error> {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.client.get_latest_blockhash()?,
+        );
+        Ok(self.client.send_and_confirm_transaction(&transaction)?)
+    }
+}
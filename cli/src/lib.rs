@@ -0,0 +1,298 @@
+//! `capnp-demo`, a command line client of the demo program.
+//!
+//! Commands are parsed into a [`Cli`] and run by [`run`] against any [`Cluster`]: the binary uses
+//! an RPC endpoint, such as a local validator, and the tests `solana-program-test`. The RPC URL
+//! and the signing keypair default to the Solana CLI config.
+
+pub mod cluster;
+pub mod output;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use capnp::message::ReaderOptions;
+use capnp::serialize;
+use capnp_models::compatibility::{check_compatibility, find_struct};
+use capnp_models::schema_capnp::code_generator_request;
+use clap::{Parser, Subcommand, ValueEnum};
+use solana_capnp_demo_client::pda::{descriptor_address, find_account_descriptor, person_address};
+use solana_capnp_demo_client::person::decode_person;
+use solana_capnp_demo_client::program::descriptor::{
+    read_descriptor, SchemaEncoding, DESCRIPTOR_MAGIC,
+};
+use solana_capnp_demo_client::{instruction, DataFormat, MessageEncoding};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+pub use cluster::{Cluster, RpcCluster};
+pub use output::Output;
+
+pub type Error = Box<dyn std::error::Error>;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "capnp-demo",
+    version,
+    about = "Creates, reads and decodes demo accounts"
+)]
+pub struct Cli {
+    /// Address the demo program is deployed at
+    #[arg(long, env = "CAPNP_DEMO_PROGRAM_ID")]
+    pub program_id: Pubkey,
+
+    /// RPC URL of the cluster [default: the Solana CLI config's]
+    #[arg(long, short = 'u', global = true)]
+    pub url: Option<String>,
+
+    /// Keypair that signs and pays for transactions [default: the Solana CLI config's]
+    #[arg(long, short = 'k', global = true)]
+    pub keypair: Option<String>,
+
+    /// Solana CLI config file to take the defaults from
+    #[arg(long, short = 'C', global = true)]
+    pub config: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human, global = true)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Human,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Creates the capnp Person of the keypair
+    InitCapnp {
+        #[arg(long)]
+        firstname: String,
+        #[arg(long)]
+        lastname: String,
+        /// Store the Person with the packed encoding
+        #[arg(long)]
+        packed: bool,
+    },
+    /// Creates the Borsh Person of the keypair
+    InitBorsh {
+        #[arg(long)]
+        firstname: String,
+        #[arg(long)]
+        lastname: String,
+    },
+    /// Replaces the names of a capnp Person, signed by its authority
+    Update {
+        #[arg(long)]
+        firstname: String,
+        #[arg(long)]
+        lastname: String,
+        /// Wallet that initialized the Person [default: the keypair]
+        #[arg(long)]
+        funding: Option<Pubkey>,
+    },
+    /// Decodes the Person account at ADDRESS
+    Show { address: Pubkey },
+    /// Prints the schema of the Person account, or descriptor, at ADDRESS
+    Schema { address: Pubkey },
+    /// Checks that a compiled schema can replace the one in a descriptor without breaking the
+    /// accounts written with it, and fails if it cannot
+    CheckSchema {
+        /// Binary CodeGeneratorRequest of the new schema, as `capnp compile -o-` writes it
+        request: PathBuf,
+        /// Struct the accounts hold
+        #[arg(long = "struct", default_value = "Person")]
+        root: String,
+        /// Descriptor holding the current schema [default: the capnp Person descriptor]
+        #[arg(long)]
+        descriptor: Option<Pubkey>,
+    },
+    /// Closes a Person account, signed by its authority, which gets the rent back
+    Close {
+        /// Wallet that initialized the Person [default: the keypair]
+        #[arg(long)]
+        funding: Option<Pubkey>,
+    },
+}
+
+/// Runs `cli`'s command against `cluster`. Commands that send a transaction call `signer` for the
+/// keypair that signs and pays for it, the others never do.
+pub fn run(
+    cli: &Cli,
+    cluster: &mut impl Cluster,
+    signer: impl FnOnce() -> Result<Keypair, Error>,
+) -> Result<Output, Error> {
+    let program_id = &cli.program_id;
+    match &cli.command {
+        Command::Show { address } => show(program_id, cluster, address),
+        Command::Schema { address } => schema(program_id, cluster, address),
+        Command::CheckSchema {
+            request,
+            root,
+            descriptor,
+        } => {
+            let descriptor =
+                descriptor.unwrap_or_else(|| descriptor_address(program_id, DataFormat::Capnp).0);
+            check_schema(program_id, cluster, &descriptor, request, root)
+        }
+        Command::InitCapnp { .. }
+        | Command::InitBorsh { .. }
+        | Command::Update { .. }
+        | Command::Close { .. } => send(cli, cluster, &signer()?),
+    }
+}
+
+/// Sends the transaction of `cli`'s command, signed by `signer`.
+fn send(cli: &Cli, cluster: &mut impl Cluster, signer: &Keypair) -> Result<Output, Error> {
+    let program_id = &cli.program_id;
+    let signer_key = signer.pubkey();
+
+    let (command, funding, instruction) = match &cli.command {
+        Command::InitCapnp {
+            firstname,
+            lastname,
+            packed,
+        } => {
+            let encoding = if *packed {
+                MessageEncoding::Packed
+            } else {
+                MessageEncoding::Unpacked
+            };
+            let instruction = instruction::initialize_capnp(
+                program_id,
+                &signer_key,
+                firstname,
+                lastname,
+                encoding,
+            );
+            ("InitializeCapnp", signer_key, instruction)
+        }
+        Command::InitBorsh {
+            firstname,
+            lastname,
+        } => {
+            let instruction =
+                instruction::initialize_borsh(program_id, &signer_key, firstname, lastname);
+            ("InitializeBorsh", signer_key, instruction)
+        }
+        Command::Update {
+            firstname,
+            lastname,
+            funding,
+        } => {
+            let funding = funding.unwrap_or(signer_key);
+            let instruction =
+                instruction::update_capnp(program_id, &signer_key, &funding, firstname, lastname);
+            ("UpdateCapnp", funding, instruction)
+        }
+        Command::Close { funding } => {
+            let funding = funding.unwrap_or(signer_key);
+            let instruction = instruction::close(program_id, &signer_key, &funding);
+            ("Close", funding, instruction)
+        }
+        Command::Show { .. } | Command::Schema { .. } | Command::CheckSchema { .. } => {
+            unreachable!("{:?} sends no transaction", cli.command)
+        }
+    };
+
+    let signature = cluster.send(instruction, signer)?;
+    Ok(Output::Sent {
+        command,
+        signature,
+        person: person_address(program_id, &funding).0,
+    })
+}
+
+/// Fetches the account at `address`, which has to belong to the program.
+fn program_account(
+    program_id: &Pubkey,
+    cluster: &mut impl Cluster,
+    address: &Pubkey,
+) -> Result<Vec<u8>, Error> {
+    let account = cluster
+        .account(address)?
+        .ok_or_else(|| format!("no account at {}", address))?;
+    if account.owner != *program_id {
+        return Err(format!("{} is not owned by {}", address, program_id).into());
+    }
+    Ok(account.data)
+}
+
+fn show(
+    program_id: &Pubkey,
+    cluster: &mut impl Cluster,
+    address: &Pubkey,
+) -> Result<Output, Error> {
+    let data = program_account(program_id, cluster, address)?;
+    let person = decode_person(&data).map_err(|error| format!("{}: {}", address, error))?;
+    let (descriptor, _) = find_account_descriptor(program_id, &data)?;
+    Ok(Output::Person {
+        address: *address,
+        descriptor,
+        person,
+    })
+}
+
+fn schema(
+    program_id: &Pubkey,
+    cluster: &mut impl Cluster,
+    address: &Pubkey,
+) -> Result<Output, Error> {
+    let data = program_account(program_id, cluster, address)?;
+    // Descriptors are read as they are, anything else through the descriptor its discriminator
+    // names.
+    let (descriptor, data) = if data.starts_with(&DESCRIPTOR_MAGIC) {
+        (*address, data)
+    } else {
+        let (descriptor, _) = find_account_descriptor(program_id, &data)
+            .map_err(|error| format!("{}: {}", address, error))?;
+        (
+            descriptor,
+            program_account(program_id, cluster, &descriptor)?,
+        )
+    };
+
+    let (header, payload) =
+        read_descriptor(&data).map_err(|error| format!("{}: {}", descriptor, error))?;
+    Ok(Output::Schema {
+        descriptor,
+        header,
+        payload: payload.to_vec(),
+    })
+}
+
+fn check_schema(
+    program_id: &Pubkey,
+    cluster: &mut impl Cluster,
+    descriptor: &Pubkey,
+    request: &Path,
+    root: &str,
+) -> Result<Output, Error> {
+    let data = program_account(program_id, cluster, descriptor)?;
+    let (header, payload) =
+        read_descriptor(&data).map_err(|error| format!("{}: {}", descriptor, error))?;
+    if header.encoding != SchemaEncoding::CapnpBinary {
+        return Err(format!("{} does not hold a compiled capnp schema", descriptor).into());
+    }
+    let bytes =
+        fs::read(request).map_err(|error| format!("reading {}: {}", request.display(), error))?;
+
+    let old = serialize::read_message(payload, ReaderOptions::new())?;
+    let old = old.get_root::<code_generator_request::Reader>()?;
+    let new = serialize::read_message(bytes.as_slice(), ReaderOptions::new())?;
+    let new = new.get_root::<code_generator_request::Reader>()?;
+    let old_struct =
+        find_struct(old, root).ok_or_else(|| format!("{} has no struct {}", descriptor, root))?;
+    let new_struct = find_struct(new, root)
+        .ok_or_else(|| format!("{} has no struct {}", request.display(), root))?;
+    let report = check_compatibility(old, old_struct, new, new_struct)?;
+    Ok(Output::Compatibility {
+        descriptor: *descriptor,
+        request: request.to_path_buf(),
+        root: root.to_string(),
+        report,
+    })
+}
@@ -0,0 +1,48 @@
+use std::process::exit;
+
+use clap::Parser;
+use solana_capnp_demo_cli::{run, Cli, Error, Output, OutputFormat, RpcCluster};
+use solana_cli_config::{Config, CONFIG_FILE};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::read_keypair_file;
+
+fn main() {
+    let cli = Cli::parse();
+    if let Err(error) = run_cli(&cli) {
+        eprintln!("error: {}", error);
+        exit(1);
+    }
+}
+
+fn run_cli(cli: &Cli) -> Result<(), Error> {
+    // Missing config files give the defaults, as they do for the Solana CLI.
+    let config = match cli.config.as_deref().or(CONFIG_FILE.as_deref()) {
+        Some(path) => Config::load(path).unwrap_or_default(),
+        None => Config::default(),
+    };
+    let url = cli.url.clone().unwrap_or(config.json_rpc_url);
+    let keypair_path = cli.keypair.clone().unwrap_or(config.keypair_path);
+    // Read-only commands work without a keypair file.
+    let signer = || {
+        read_keypair_file(&keypair_path)
+            .map_err(|error| Error::from(format!("reading keypair {}: {}", keypair_path, error)))
+    };
+    let commitment = config
+        .commitment
+        .parse()
+        .unwrap_or_else(|_| CommitmentConfig::confirmed());
+
+    let mut cluster = RpcCluster::new(url, commitment);
+    let output = run(cli, &mut cluster, signer)?;
+    match cli.output {
+        OutputFormat::Human => println!("{}", output),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&output.to_json())?),
+    }
+    // So scripts and CI stop before deploying a schema that breaks existing accounts.
+    if let Output::Compatibility { report, .. } = &output {
+        if !report.is_compatible() {
+            exit(2);
+        }
+    }
+    Ok(())
+}
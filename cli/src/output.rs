@@ -0,0 +1,222 @@
+//! What commands print, as text for people or as JSON for scripts.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use capnp_models::compatibility::CompatibilityReport;
+use serde_json::{json, Value};
+use solana_capnp_demo_client::person::Person;
+use solana_capnp_demo_client::program::descriptor::{DescriptorHeader, SchemaEncoding};
+use solana_capnp_demo_client::{DataFormat, MessageEncoding};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// The result of a command.
+#[derive(Debug)]
+pub enum Output {
+    /// A transaction changed the Person account at `person`.
+    Sent {
+        command: &'static str,
+        signature: Signature,
+        person: Pubkey,
+    },
+    Person {
+        address: Pubkey,
+        descriptor: Pubkey,
+        person: Person,
+    },
+    Schema {
+        descriptor: Pubkey,
+        header: DescriptorHeader,
+        payload: Vec<u8>,
+    },
+    /// How the schema compiled into `request` compares with the one in `descriptor`.
+    Compatibility {
+        descriptor: Pubkey,
+        request: PathBuf,
+        root: String,
+        report: CompatibilityReport,
+    },
+}
+
+impl Output {
+    pub fn to_json(&self) -> Value {
+        match self {
+            Output::Sent {
+                command,
+                signature,
+                person,
+            } => json!({
+                "command": command,
+                "signature": signature.to_string(),
+                "person": person.to_string(),
+            }),
+            Output::Person {
+                address,
+                descriptor,
+                person,
+            } => {
+                let details = person.details.as_ref().map(|details| {
+                    json!({
+                        "email": details.email,
+                        "birth_year": details.birth_year,
+                        "address": details.address.as_ref().map(|address| json!({
+                            "street": address.street,
+                            "city": address.city,
+                            "postal_code": address.postal_code,
+                            "country": address.country,
+                        })),
+                    })
+                });
+                json!({
+                    "address": address.to_string(),
+                    "descriptor": descriptor.to_string(),
+                    "format": format_name(person.format),
+                    "version": person.version,
+                    "encoding": person.encoding.map(encoding_name),
+                    "firstname": person.firstname,
+                    "lastname": person.lastname,
+                    "authority": person.authority.map(|authority| authority.to_string()),
+                    "details": details,
+                })
+            }
+            Output::Schema {
+                descriptor,
+                header,
+                payload,
+            } => {
+                let schema = match header.encoding {
+                    SchemaEncoding::CapnpText => json!(String::from_utf8_lossy(payload)),
+                    _ => json!(hex(payload)),
+                };
+                json!({
+                    "descriptor": descriptor.to_string(),
+                    "encoding": schema_encoding_name(header.encoding),
+                    "length": header.payload_len,
+                    "sha256": hex(&header.payload_hash),
+                    "schema": schema,
+                })
+            }
+            Output::Compatibility {
+                descriptor,
+                request,
+                root,
+                report,
+            } => {
+                let incompatibilities: Vec<Value> = report
+                    .incompatibilities
+                    .iter()
+                    .map(|incompatibility| {
+                        json!({
+                            "path": incompatibility.path,
+                            "change": incompatibility.change.to_string(),
+                        })
+                    })
+                    .collect();
+                json!({
+                    "descriptor": descriptor.to_string(),
+                    "request": request.display().to_string(),
+                    "struct": root,
+                    "compatible": report.is_compatible(),
+                    "incompatibilities": incompatibilities,
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Sent {
+                command,
+                signature,
+                person,
+            } => {
+                writeln!(f, "{}: {}", command, signature)?;
+                write!(f, "Person: {}", person)
+            }
+            Output::Person {
+                address,
+                descriptor,
+                person,
+            } => {
+                writeln!(f, "Person:      {}", address)?;
+                write!(f, "Format:      {}", format_name(person.format))?;
+                if let Some(encoding) = person.encoding {
+                    write!(f, " ({})", encoding_name(encoding))?;
+                }
+                writeln!(f, ", schema version {}", person.version)?;
+                writeln!(f, "Name:        {} {}", person.firstname, person.lastname)?;
+                match person.authority {
+                    Some(authority) => writeln!(f, "Authority:   {}", authority)?,
+                    None => writeln!(f, "Authority:   none")?,
+                }
+                if let Some(details) = &person.details {
+                    writeln!(f, "Email:       {}", details.email)?;
+                    writeln!(f, "Birth year:  {}", details.birth_year)?;
+                    if let Some(address) = &details.address {
+                        writeln!(
+                            f,
+                            "Address:     {}, {} {}, {}",
+                            address.street, address.postal_code, address.city, address.country
+                        )?;
+                    }
+                }
+                write!(f, "Descriptor:  {}", descriptor)
+            }
+            Output::Schema {
+                descriptor,
+                header,
+                payload,
+            } => {
+                writeln!(f, "Descriptor:  {}", descriptor)?;
+                writeln!(f, "Encoding:    {}", schema_encoding_name(header.encoding))?;
+                writeln!(f, "Length:      {} bytes", header.payload_len)?;
+                writeln!(f, "SHA-256:     {}", hex(&header.payload_hash))?;
+                writeln!(f)?;
+                match header.encoding {
+                    SchemaEncoding::CapnpText => write!(f, "{}", String::from_utf8_lossy(payload)),
+                    _ => write!(f, "{}", hex(payload)),
+                }
+            }
+            Output::Compatibility {
+                descriptor,
+                request,
+                root,
+                report,
+            } => {
+                writeln!(f, "Descriptor:  {}", descriptor)?;
+                writeln!(f, "Schema:      {}", request.display())?;
+                writeln!(f, "Struct:      {}", root)?;
+                write!(f, "Result:      {}", report)
+            }
+        }
+    }
+}
+
+fn format_name(format: DataFormat) -> &'static str {
+    match format {
+        DataFormat::Capnp => "capnp",
+        DataFormat::Borsh => "borsh",
+    }
+}
+
+fn encoding_name(encoding: MessageEncoding) -> &'static str {
+    match encoding {
+        MessageEncoding::Unpacked => "unpacked",
+        MessageEncoding::Packed => "packed",
+    }
+}
+
+fn schema_encoding_name(encoding: SchemaEncoding) -> &'static str {
+    match encoding {
+        SchemaEncoding::CapnpText => "capnp-text",
+        SchemaEncoding::CapnpBinary => "capnp-binary",
+        SchemaEncoding::Borsh => "borsh",
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
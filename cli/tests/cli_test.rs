@@ -0,0 +1,328 @@
+use capnp_models::PERSON_SCHEMA_REQUESTS;
+use clap::Parser;
+use serde_json::Value;
+use solana_capnp_demo_cli::{run, Cli, Cluster, Error, Output};
+use solana_capnp_demo_client::pda::{descriptor_address, person_address};
+use solana_capnp_demo_client::program::instruction as process_instruction;
+use solana_capnp_demo_client::DataFormat;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use tokio::runtime::Runtime;
+
+/// The program in `solana-program-test`, driven synchronously the way the CLI drives an RPC node.
+struct BanksCluster {
+    runtime: Runtime,
+    banks_client: BanksClient,
+}
+
+impl BanksCluster {
+    fn start(program_id: Pubkey, wallets: &[&Keypair]) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let mut program_test = ProgramTest::new(
+            "solana_capnp_demo",
+            program_id,
+            processor!(process_instruction),
+        );
+        for wallet in wallets {
+            program_test.add_account(
+                wallet.pubkey(),
+                Account::new(10_000_000_000, 0, &system_program::id()),
+            );
+        }
+        let (banks_client, _, _) = runtime.block_on(program_test.start());
+        BanksCluster {
+            runtime,
+            banks_client,
+        }
+    }
+}
+
+impl Cluster for BanksCluster {
+    fn account(&mut self, address: &Pubkey) -> Result<Option<Account>, Error> {
+        Ok(self
+            .runtime
+            .block_on(self.banks_client.get_account(*address))?)
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<Signature, Error> {
+        let recent_blockhash = self
+            .runtime
+            .block_on(self.banks_client.get_latest_blockhash())?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            recent_blockhash,
+        );
+        let signature = transaction.signatures[0];
+        self.runtime
+            .block_on(self.banks_client.process_transaction(transaction))?;
+        Ok(signature)
+    }
+}
+
+/// Hands `run` a copy of `wallet`, as the binary hands it the keypair file it reads.
+fn keypair(wallet: &Keypair) -> impl FnOnce() -> Result<Keypair, Error> + '_ {
+    || Ok(Keypair::from_bytes(&wallet.to_bytes())?)
+}
+
+/// Runs `capnp-demo` with `args` after the program ID and returns what it would print as JSON.
+fn capnp_demo(
+    cluster: &mut BanksCluster,
+    program_id: &Pubkey,
+    signer: &Keypair,
+    args: &[&str],
+) -> Result<Value, Error> {
+    let program_id = program_id.to_string();
+    let cli = Cli::try_parse_from(
+        [
+            "capnp-demo",
+            "--program-id",
+            program_id.as_str(),
+            "--output",
+            "json",
+        ]
+        .iter()
+        .chain(args),
+    )?;
+    Ok(run(&cli, cluster, keypair(signer))?.to_json())
+}
+
+#[test]
+fn creates_updates_shows_and_closes_a_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let wallet = Keypair::new();
+    let mut cluster = BanksCluster::start(program_id, &[&wallet]);
+    let (person, _) = person_address(&program_id, &wallet.pubkey());
+    let person = person.to_string();
+
+    let sent = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &[
+            "init-capnp",
+            "--firstname",
+            "Captain",
+            "--lastname",
+            "Proto",
+            "--packed",
+        ],
+    )
+    .unwrap();
+    assert_eq!(sent["command"], "InitializeCapnp");
+    assert_eq!(sent["person"], person.as_str());
+
+    capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["update", "--firstname", "Commander", "--lastname", "Proto"],
+    )
+    .unwrap();
+
+    let shown = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["show", person.as_str()],
+    )
+    .unwrap();
+    assert_eq!(shown["format"], "capnp");
+    assert_eq!(shown["encoding"], "packed");
+    assert_eq!(shown["version"], 1);
+    assert_eq!(shown["firstname"], "Commander");
+    assert_eq!(shown["authority"], wallet.pubkey().to_string().as_str());
+    assert_eq!(
+        shown["descriptor"],
+        descriptor_address(&program_id, DataFormat::Capnp)
+            .0
+            .to_string()
+            .as_str()
+    );
+
+    capnp_demo(&mut cluster, &program_id, &wallet, &["close"]).unwrap();
+    let error = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["show", person.as_str()],
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), format!("no account at {}", person));
+}
+
+#[test]
+fn prints_the_schema_of_a_person_or_descriptor() {
+    let program_id = Pubkey::new_unique();
+    let wallet = Keypair::new();
+    let mut cluster = BanksCluster::start(program_id, &[&wallet]);
+    capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["init-borsh", "--firstname", "John", "--lastname", "Borsh"],
+    )
+    .unwrap();
+    // The Borsh Person's schema comes from the Borsh descriptor.
+    let person = person_address(&program_id, &wallet.pubkey()).0.to_string();
+    let descriptor = descriptor_address(&program_id, DataFormat::Borsh)
+        .0
+        .to_string();
+    let from_person = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["schema", person.as_str()],
+    )
+    .unwrap();
+    assert_eq!(from_person["descriptor"], descriptor.as_str());
+    assert_eq!(from_person["encoding"], "borsh");
+    let from_descriptor = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["schema", descriptor.as_str()],
+    )
+    .unwrap();
+    assert_eq!(from_descriptor, from_person);
+
+    let shown = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["show", person.as_str()],
+    )
+    .unwrap();
+    assert_eq!(shown["format"], "borsh");
+    assert_eq!(shown["encoding"], Value::Null);
+
+    // Reading needs no keypair.
+    let program_id_arg = program_id.to_string();
+    for command in ["show", "schema"] {
+        let cli = Cli::try_parse_from([
+            "capnp-demo",
+            "--program-id",
+            program_id_arg.as_str(),
+            command,
+            person.as_str(),
+        ])
+        .unwrap();
+        run(&cli, &mut cluster, || Err("no keypair".into())).unwrap();
+    }
+}
+
+#[test]
+fn checks_a_compiled_schema_against_the_descriptor() {
+    let program_id = Pubkey::new_unique();
+    let wallet = Keypair::new();
+    let mut cluster = BanksCluster::start(program_id, &[&wallet]);
+    capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &[
+            "init-capnp",
+            "--firstname",
+            "Captain",
+            "--lastname",
+            "Proto",
+        ],
+    )
+    .unwrap();
+    let dir = std::env::temp_dir().join(format!("cli_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let v1 = dir.join("person_capnp.bin");
+    let v2 = dir.join("person_v2_capnp.bin");
+    std::fs::write(&v1, PERSON_SCHEMA_REQUESTS[0]).unwrap();
+    std::fs::write(&v2, PERSON_SCHEMA_REQUESTS[1]).unwrap();
+
+    // The descriptor holds v2, which can replace itself but not go back to v1.
+    let same = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["check-schema", v2.to_str().unwrap()],
+    )
+    .unwrap();
+    assert_eq!(
+        same["descriptor"],
+        descriptor_address(&program_id, DataFormat::Capnp)
+            .0
+            .to_string()
+            .as_str()
+    );
+    assert_eq!(same["struct"], "Person");
+    assert_eq!(same["compatible"], true);
+    assert_eq!(same["incompatibilities"], serde_json::json!([]));
+
+    let older = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["check-schema", v1.to_str().unwrap()],
+    )
+    .unwrap();
+    assert_eq!(older["compatible"], false);
+    assert_eq!(
+        older["incompatibilities"][2],
+        serde_json::json!({ "path": "Person.email", "change": "field @2 removed" })
+    );
+
+    let error = capnp_demo(
+        &mut cluster,
+        &program_id,
+        &wallet,
+        &["check-schema", v2.to_str().unwrap(), "--struct", "Account"],
+    )
+    .unwrap_err();
+    assert!(
+        error.to_string().ends_with("has no struct Account"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn human_output_names_the_person() {
+    let program_id = Pubkey::new_unique();
+    let wallet = Keypair::new();
+    let mut cluster = BanksCluster::start(program_id, &[&wallet]);
+    let person = person_address(&program_id, &wallet.pubkey()).0.to_string();
+    let program_id_arg = program_id.to_string();
+
+    let cli = Cli::try_parse_from([
+        "capnp-demo",
+        "--program-id",
+        program_id_arg.as_str(),
+        "init-capnp",
+        "--firstname",
+        "Captain",
+        "--lastname",
+        "Proto",
+    ])
+    .unwrap();
+    run(&cli, &mut cluster, keypair(&wallet)).unwrap();
+
+    let cli = Cli::try_parse_from([
+        "capnp-demo",
+        "--program-id",
+        program_id_arg.as_str(),
+        "show",
+        person.as_str(),
+    ])
+    .unwrap();
+    let output = run(&cli, &mut cluster, keypair(&wallet)).unwrap();
+    assert!(matches!(output, Output::Person { .. }));
+    let text = output.to_string();
+    assert!(text.contains("Format:      capnp (unpacked), schema version 1"));
+    assert!(text.contains("Name:        Captain Proto"));
+}
@@ -9,6 +9,7 @@ import {
 } from "@solana/web3.js";
 import { Buffer } from 'buffer';
 import * as borsh from '@project-serum/borsh'
+import { encodeDeserializeBorsh, encodeInitializeBorsh, findDescriptorAddress } from "./demo_instruction";
 
 const PAYER_KEYPAIR = Keypair.generate();
 
@@ -27,25 +28,24 @@ const PAYER_KEYPAIR = Keypair.generate();
         programId
     );
 
-    let disc = await getDiscriminator("person", "account");
-    console.log(disc);
-
-    const [pda_descriptor, _] = await PublicKey.findProgramAddressSync(
-        [Buffer.from(disc)],
-        programId
-    );
+    // The "descriptor account" holding the schema of Borsh encoded "person" accounts
+    const [pda_descriptor, _] = findDescriptorAddress(programId, "borsh", "person");
 
     console.log(`PDA Pubkey: ${pda.toString()}`);
 
-    const instructionSchema = borsh.struct([
-        borsh.u8('variant'),
-        borsh.u32('bump')
+    // The instruction data carries the Borsh encoded PersonState to store
+    const personStateSchema = borsh.struct([
+        borsh.bool('isInitialized'),
+        borsh.str('firstName'),
+        borsh.str('lastName'),
+        // The program makes the payer the authority, whatever is sent here
+        borsh.publicKey('authority')
     ])
 
     const buffer = Buffer.alloc(1000)
-    instructionSchema.encode({ variant: 2, bump: bump}, buffer)
+    personStateSchema.encode({ isInitialized: true, firstName: "John", lastName: "Borsh", authority: PAYER_KEYPAIR.publicKey }, buffer)
 
-    const instructionBuffer = buffer.slice(0, instructionSchema.getSpan(buffer))
+    const instructionBuffer = encodeInitializeBorsh(bump, buffer.slice(0, personStateSchema.getSpan(buffer)))
 //Buffer.from(Uint8Array.of(bump))
     const createPDAIx = new TransactionInstruction({
         programId: programId,
@@ -87,14 +87,7 @@ const PAYER_KEYPAIR = Keypair.generate();
     console.log("TX 2");
     await sleep(1000);
     //2nd instruction
-    const instructionSchema2 = borsh.struct([
-        borsh.u8('variant')
-    ])
-
-    const buffer2 = Buffer.alloc(1000)
-    instructionSchema2.encode({ variant: 3 }, buffer2)
-
-    const instructionBuffer2 = buffer2.slice(0, instructionSchema2.getSpan(buffer2))
+    const instructionBuffer2 = encodeDeserializeBorsh()
 //Buffer.from(Uint8Array.of(bump))
     const createPDAIx2 = new TransactionInstruction({
         programId: programId,
@@ -134,11 +127,4 @@ const PAYER_KEYPAIR = Keypair.generate();
     console.log(JSON.stringify(pda_descriptor_account2));
 })();
 
-//TODO set to GC url
-async function getDiscriminator(account_name: string, account_type: string): Promise<Uint8Array> {
-    const response = await fetch("https://test.api.brytelands.io/discriminator-offline/" + account_name + "/" + account_type);
-    // @ts-ignore
-    return await response.json();
-}
-
 export const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
\ No newline at end of file
@@ -8,7 +8,7 @@ import {
     TransactionInstruction,
 } from "@solana/web3.js";
 import {Buffer} from 'buffer';
-import * as borsh from '@project-serum/borsh'
+import {encodeDeserializeCapnp, encodeInitializeCapnp, findDescriptorAddress} from "./demo_instruction";
 
 const PAYER_KEYPAIR = Keypair.generate();
 
@@ -28,29 +28,14 @@ const PAYER_KEYPAIR = Keypair.generate();
         programId
     );
 
-    // Calculate the discriminator based on the account struct name, in this case, "person" and it's an "account" type (Look in the program's lib.rs).
-    // This is used to look up the "descriptor account" that holds the schema for your primary account
-    let disc = await getDiscriminator("person", "account");
-    console.log(disc);
-
-    const [pda_descriptor, _] = await PublicKey.findProgramAddressSync(
-        [Buffer.from(disc)],
-        programId
-    );
+    // The "descriptor account" holding the schema of capnp encoded "person" accounts
+    const [pda_descriptor, _] = findDescriptorAddress(programId, "capnp", "person");
 
     console.log(`Account Pubkey: ${pda.toString()}`);
     console.log(`Account Descriptor Pubkey: ${pda_descriptor.toString()}`);
 
-    const instructionSchema = borsh.struct([
-        borsh.u8('variant'),
-        borsh.u32('bump')
-    ])
-
-    const buffer = Buffer.alloc(1000)
-    // Create instruction for the canpn initialize instruction (variant 0)
-    instructionSchema.encode({variant: 0, bump: bump}, buffer)
-
-    const instructionBuffer = buffer.slice(0, instructionSchema.getSpan(buffer))
+    // Create the capnp initialize instruction, carrying the Person to store
+    const instructionBuffer = encodeInitializeCapnp(bump, "Captain", "Proto")
     const createPDAIx = new TransactionInstruction({
         programId: programId,
         data: instructionBuffer,
@@ -97,15 +82,8 @@ const PAYER_KEYPAIR = Keypair.generate();
     await sleep(1000);
 
     //2nd instruction
-    const deserializeCapnpDemoInstruction = borsh.struct([
-        borsh.u8('variant')
-    ])
-
-    const buffer2 = Buffer.alloc(1000)
     // Create the instruction to deserialize the newly created account data on-chain
-    deserializeCapnpDemoInstruction.encode({variant: 1}, buffer2)
-
-    const deserializeCapnpDemoInstructionBuffer = buffer2.slice(0, deserializeCapnpDemoInstruction.getSpan(buffer2))
+    const deserializeCapnpDemoInstructionBuffer = encodeDeserializeCapnp()
     const createPDAIx2 = new TransactionInstruction({
         programId: programId,
         data: deserializeCapnpDemoInstructionBuffer,
@@ -144,10 +122,5 @@ const PAYER_KEYPAIR = Keypair.generate();
     console.log(JSON.stringify(pda_descriptor_account2));
 })();
 
-async function getDiscriminator(account_name: string, account_type: string): Promise<Uint8Array> {
-    const response = await fetch("https://test.api.brytelands.io/discriminator-offline/" + account_name + "/" + account_type);
-    // @ts-ignore
-    return await response.json();
-}
 
 export const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
\ No newline at end of file
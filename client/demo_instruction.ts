@@ -0,0 +1,188 @@
+import {PublicKey} from "@solana/web3.js";
+import {Buffer} from 'buffer';
+
+// Union discriminants of DemoInstruction (see capnp-models/src/instruction.capnp).
+export enum DemoInstruction {
+    InitializeCapnp = 0,
+    DeserializeCapnp = 1,
+    InitializeBorsh = 2,
+    DeserializeBorsh = 3,
+    UpdateCapnp = 4,
+    Close = 5,
+    CloseDescriptor = 6,
+    MigratePerson = 7,
+    SetAuthority = 8,
+    ConvertBorshToCapnp = 9,
+    ConvertCapnpToBorsh = 10,
+    BeginSchemaUpload = 11,
+    WriteSchemaChunk = 12,
+    FinalizeSchema = 13,
+    CancelSchemaUpload = 14,
+}
+
+// Builds the single segment, unpacked capnp messages the program reads its instructions from.
+class MessageWriter {
+    segment = Buffer.alloc(0);
+
+    allocate(words: number): number {
+        const start = this.segment.length / 8;
+        this.segment = Buffer.concat([this.segment, Buffer.alloc(words * 8)]);
+        return start;
+    }
+
+    // Points the pointer at `pointerWord` to a new struct and returns the struct's first word.
+    initStruct(pointerWord: number, dataWords: number, pointers: number): number {
+        const start = this.allocate(dataWords + pointers);
+        // Offsets are relative to the end of the pointer
+        this.segment.writeInt32LE((start - pointerWord - 1) << 2, pointerWord * 8);
+        this.segment.writeUInt16LE(dataWords, pointerWord * 8 + 4);
+        this.segment.writeUInt16LE(pointers, pointerWord * 8 + 6);
+        return start;
+    }
+
+    // Points the pointer at `pointerWord` to a new byte list holding `bytes`.
+    setBytes(pointerWord: number, bytes: Buffer) {
+        const start = this.allocate(Math.ceil(bytes.length / 8));
+        this.segment.writeInt32LE(((start - pointerWord - 1) << 2) | 1, pointerWord * 8);
+        this.segment.writeUInt32LE((bytes.length << 3) | 2, pointerWord * 8 + 4);
+        bytes.copy(this.segment, start * 8);
+    }
+
+    setText(pointerWord: number, text: string) {
+        this.setBytes(pointerWord, Buffer.from(text + "\0"));
+    }
+
+    toBuffer(): Buffer {
+        // Segment table: segment count - 1, then the segment size in words
+        const table = Buffer.alloc(8);
+        table.writeUInt32LE(0, 0);
+        table.writeUInt32LE(this.segment.length / 8, 4);
+        return Buffer.concat([table, this.segment]);
+    }
+}
+
+// Encodes a DemoInstruction. Every parameter struct has the bump in its data section and at most
+// one pointer, which `setPointer` fills in.
+function encodeInstruction(
+    which: DemoInstruction,
+    bump?: number,
+    setPointer?: (writer: MessageWriter, pointerWord: number) => void,
+): Buffer {
+    const writer = new MessageWriter();
+    const root = writer.allocate(1);
+    const instruction = writer.initStruct(root, 1, 1);
+    writer.segment.writeUInt16LE(which, instruction * 8);
+
+    if (bump !== undefined) {
+        const params = writer.initStruct(instruction + 1, 1, setPointer ? 1 : 0);
+        writer.segment.writeUInt8(bump, params * 8);
+        if (setPointer) {
+            setPointer(writer, params + 1);
+        }
+    }
+
+    return writer.toBuffer();
+}
+
+function setPerson(firstname: string, lastname: string) {
+    return (writer: MessageWriter, pointerWord: number) => {
+        const person = writer.initStruct(pointerWord, 0, 2);
+        writer.setText(person, firstname);
+        writer.setText(person + 1, lastname);
+    };
+}
+
+// With `packed`, the program stores the Person with the packed capnp encoding.
+export function encodeInitializeCapnp(bump: number, firstname: string, lastname: string, packed = false): Buffer {
+    return encodeInstruction(DemoInstruction.InitializeCapnp, bump, (writer, pointerWord) => {
+        // `packed` is bit 8 of the params' data word, right after the bump.
+        writer.segment.writeUInt8(packed ? 1 : 0, (pointerWord - 1) * 8 + 1);
+        setPerson(firstname, lastname)(writer, pointerWord);
+    });
+}
+
+export function encodeUpdateCapnp(bump: number, firstname: string, lastname: string): Buffer {
+    return encodeInstruction(DemoInstruction.UpdateCapnp, bump, setPerson(firstname, lastname));
+}
+
+// `personState` is the Borsh encoded PersonState to store.
+export function encodeInitializeBorsh(bump: number, personState: Buffer): Buffer {
+    return encodeInstruction(DemoInstruction.InitializeBorsh, bump, (writer, pointerWord) =>
+        writer.setBytes(pointerWord, personState)
+    );
+}
+
+export function encodeClose(bump: number): Buffer {
+    return encodeInstruction(DemoInstruction.Close, bump);
+}
+
+export function encodeDeserializeCapnp(): Buffer {
+    return encodeInstruction(DemoInstruction.DeserializeCapnp);
+}
+
+export function encodeDeserializeBorsh(): Buffer {
+    return encodeInstruction(DemoInstruction.DeserializeBorsh);
+}
+
+// Hands a Person account off to `newAuthority`. The current authority signs the transaction.
+export function encodeSetAuthority(bump: number, newAuthority: PublicKey): Buffer {
+    return encodeInstruction(DemoInstruction.SetAuthority, bump, (writer, pointerWord) =>
+        writer.setBytes(pointerWord, newAuthority.toBuffer())
+    );
+}
+
+export function encodeCloseDescriptor(): Buffer {
+    return encodeInstruction(DemoInstruction.CloseDescriptor);
+}
+
+// Rewrites a Borsh Person account as a capnp Person, packed if `packed` is set. The authority
+// signs the transaction.
+export function encodeConvertBorshToCapnp(bump: number, packed = false): Buffer {
+    const message = encodeInstruction(DemoInstruction.ConvertBorshToCapnp, bump);
+    // The params are the last word of the message; `packed` is bit 8, right after the bump.
+    message.writeUInt8(packed ? 1 : 0, message.length - 8 + 1);
+    return message;
+}
+
+// Rewrites a version 1 capnp Person account as a Borsh PersonState. The authority signs the
+// transaction.
+export function encodeConvertCapnpToBorsh(bump: number): Buffer {
+    return encodeInstruction(DemoInstruction.ConvertCapnpToBorsh, bump);
+}
+
+export interface Address {
+    street: string;
+    city: string;
+    postalCode: string;
+    country: string;
+}
+
+// MigratePerson carries two pointers, the email and the address, so it is laid out by hand.
+export function encodeMigratePerson(bump: number, email: string, birthYear: number, address?: Address): Buffer {
+    const writer = new MessageWriter();
+    const root = writer.allocate(1);
+    const instruction = writer.initStruct(root, 1, 1);
+    writer.segment.writeUInt16LE(DemoInstruction.MigratePerson, instruction * 8);
+
+    const params = writer.initStruct(instruction + 1, 1, 2);
+    writer.segment.writeUInt8(bump, params * 8);
+    writer.segment.writeUInt16LE(birthYear, params * 8 + 2);
+    writer.setText(params + 1, email);
+    if (address) {
+        const fields = writer.initStruct(params + 2, 0, 4);
+        writer.setText(fields, address.street);
+        writer.setText(fields + 1, address.city);
+        writer.setText(fields + 2, address.postalCode);
+        writer.setText(fields + 3, address.country);
+    }
+
+    return writer.toBuffer();
+}
+
+// Descriptor addresses are derived from ["descriptor", format, type name] (see program/src/descriptor.rs).
+export function findDescriptorAddress(programId: PublicKey, format: "capnp" | "borsh", typeName: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("descriptor"), Buffer.from(format), Buffer.from(typeName)],
+        programId
+    );
+}
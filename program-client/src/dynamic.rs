@@ -0,0 +1,320 @@
+//! Decoding of capnp messages with a schema read at runtime, for explorers and indexers that
+//! have no generated code for the program's types.
+//!
+//! A [`SchemaDecoder`] follows the nodes of the `CodeGeneratorRequest` the capnp compiler builds
+//! out of a published schema and turns a message into JSON:
+//!
+//! - structs become objects keyed by field name, with only the active member of a union and
+//!   groups as nested objects
+//! - enums become the name of their enumerant, or its number if the schema doesn't have it
+//! - `Data` becomes an array of bytes
+//! - data fields read as their defaults when the message doesn't have them
+//! - null pointers become `null`, unless the field has an explicit default, which is decoded
+//!   instead
+//! - interfaces and `AnyPointer`s have no schema to follow and become `null`
+//!
+//! capnp's public readers are all generated for a schema known at compile time, and its dynamic
+//! reflection only covers schemas compiled in as well, so the decoder reads structs and lists
+//! through `capnp::private::layout`. That module is outside capnp's semver guarantees, which is
+//! why capnp-models pins capnp to an exact version: bumping it means checking this module against
+//! the new release.
+
+use std::collections::HashMap;
+
+use capnp::message::{Reader, ReaderSegments};
+use capnp::private::layout::{ElementSize, PointerReader, StructReader};
+use capnp::traits::FromPointerReader;
+use capnp::{data, data_list, primitive_list, text, text_list, Word};
+use capnp_models::compatibility;
+use capnp_models::schema_capnp::{code_generator_request, field, node, type_, value};
+use serde_json::{Map, Value};
+use solana_capnp_demo::account::{read_capnp_message, CAPNP_ACCOUNT_MIN_LEN};
+
+pub use capnp_models::schema_capnp;
+
+/// Decodes messages of any struct the `CodeGeneratorRequest` it was made from declares.
+pub struct SchemaDecoder<'a> {
+    request: code_generator_request::Reader<'a>,
+    nodes: HashMap<u64, node::Reader<'a>>,
+}
+
+impl<'a> SchemaDecoder<'a> {
+    pub fn new(request: code_generator_request::Reader<'a>) -> capnp::Result<Self> {
+        let nodes = request
+            .get_nodes()?
+            .iter()
+            .map(|node| (node.get_id(), node))
+            .collect();
+        Ok(SchemaDecoder { request, nodes })
+    }
+
+    /// ID of the struct `name`, see [`compatibility::find_struct`].
+    pub fn find_struct(&self, name: &str) -> Option<u64> {
+        compatibility::find_struct(self.request, name)
+    }
+
+    /// Decodes `message`, whose root is a struct `node_id`.
+    pub fn decode_message<S: ReaderSegments>(
+        &self,
+        node_id: u64,
+        message: &Reader<S>,
+    ) -> capnp::Result<Value> {
+        let root: RawPointer = message.get_root()?;
+        self.decode_struct(node_id, root.0.get_struct(None)?)
+    }
+
+    /// Decodes the message of a capnp account, in whichever encoding its header names, whose
+    /// root is a struct `node_id`. The account's discriminator isn't checked, any capnp account
+    /// of the program decodes with the schema of its descriptor.
+    pub fn decode_account(&self, node_id: u64, data: &[u8]) -> capnp::Result<Value> {
+        if data.len() < CAPNP_ACCOUNT_MIN_LEN {
+            return Err(capnp::Error::failed(format!(
+                "a capnp account has at least {} bytes, this one {}",
+                CAPNP_ACCOUNT_MIN_LEN,
+                data.len()
+            )));
+        }
+        let message = read_capnp_message(data)
+            .map_err(|error| capnp::Error::failed(format!("reading the message: {}", error)))?;
+        let root: RawPointer = message.get_root()?;
+        self.decode_struct(node_id, root.0.get_struct(None)?)
+    }
+
+    fn node(&self, node_id: u64) -> capnp::Result<node::Reader<'a>> {
+        self.nodes
+            .get(&node_id)
+            .copied()
+            .ok_or_else(|| capnp::Error::failed(format!("no node {:#x} in the schema", node_id)))
+    }
+
+    fn decode_struct(&self, node_id: u64, reader: StructReader<'_>) -> capnp::Result<Value> {
+        let struct_node = match self.node(node_id)?.which()? {
+            node::Struct(struct_node) => struct_node,
+            _ => return Err(not_a(node_id, "a struct")),
+        };
+        // Fields outside the union have no discriminant value.
+        let discriminant = if struct_node.get_discriminant_count() > 0 {
+            reader.get_data_field::<u16>(struct_node.get_discriminant_offset() as usize)
+        } else {
+            field::NO_DISCRIMINANT
+        };
+
+        let mut object = Map::new();
+        for field in struct_node.get_fields()? {
+            let field_discriminant = field.get_discriminant_value();
+            if field_discriminant != field::NO_DISCRIMINANT && field_discriminant != discriminant {
+                continue;
+            }
+            let value = match field.which()? {
+                field::Slot(slot) => self.decode_slot(reader, slot)?,
+                // Groups are stored in the struct that has them.
+                field::Group(group) => self.decode_struct(group.get_type_id(), reader)?,
+            };
+            object.insert(field.get_name()?.to_str()?.to_string(), value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn decode_slot(
+        &self,
+        reader: StructReader<'_>,
+        slot: field::slot::Reader<'_>,
+    ) -> capnp::Result<Value> {
+        let offset = slot.get_offset() as usize;
+        let field_type = slot.get_type()?;
+        let default = slot.get_default_value()?.which()?;
+
+        // Data fields are stored XORed with their defaults.
+        macro_rules! data_field {
+            ($ty:ty, $variant:ident) => {{
+                let default = match default {
+                    value::$variant(default) => default,
+                    _ => 0,
+                };
+                Value::from(reader.get_data_field::<$ty>(offset) ^ default)
+            }};
+        }
+        macro_rules! float_field {
+            ($ty:ty, $bits:ty, $variant:ident) => {{
+                let default = match default {
+                    value::$variant(default) => default.to_bits(),
+                    _ => 0,
+                };
+                Value::from(<$ty>::from_bits(
+                    reader.get_data_field::<$bits>(offset) ^ default,
+                ))
+            }};
+        }
+
+        Ok(match field_type.which()? {
+            type_::Void(()) => Value::Null,
+            type_::Bool(()) => {
+                Value::from(reader.get_bool_field(offset) ^ matches!(default, value::Bool(true)))
+            }
+            type_::Int8(()) => data_field!(i8, Int8),
+            type_::Int16(()) => data_field!(i16, Int16),
+            type_::Int32(()) => data_field!(i32, Int32),
+            type_::Int64(()) => data_field!(i64, Int64),
+            type_::Uint8(()) => data_field!(u8, Uint8),
+            type_::Uint16(()) => data_field!(u16, Uint16),
+            type_::Uint32(()) => data_field!(u32, Uint32),
+            type_::Uint64(()) => data_field!(u64, Uint64),
+            type_::Float32(()) => float_field!(f32, u32, Float32),
+            type_::Float64(()) => float_field!(f64, u64, Float64),
+            type_::Enum(enum_type) => {
+                let default = match default {
+                    value::Enum(default) => default,
+                    _ => 0,
+                };
+                self.enumerant(
+                    enum_type.get_type_id(),
+                    reader.get_data_field::<u16>(offset) ^ default,
+                )?
+            }
+            _ => {
+                let pointer = reader.get_pointer_field(offset);
+                if !pointer.is_null() {
+                    self.decode_pointer(field_type, pointer)?
+                } else if !slot.get_had_explicit_default() {
+                    Value::Null
+                } else {
+                    match default {
+                        value::Text(text) => Value::from(text?.to_str()?),
+                        value::Data(data) => bytes(data?),
+                        value::List(default) | value::Struct(default) => {
+                            let default: RawPointer = default.get_as()?;
+                            self.decode_pointer(field_type, default.0)?
+                        }
+                        _ => Value::Null,
+                    }
+                }
+            }
+        })
+    }
+
+    /// Decodes the non-null `pointer` to a `pointer_type` value.
+    fn decode_pointer(
+        &self,
+        pointer_type: type_::Reader<'_>,
+        pointer: PointerReader<'_>,
+    ) -> capnp::Result<Value> {
+        Ok(match pointer_type.which()? {
+            type_::Text(()) => {
+                Value::from(text::Reader::get_from_pointer(&pointer, None)?.to_str()?)
+            }
+            type_::Data(()) => bytes(data::Reader::get_from_pointer(&pointer, None)?),
+            type_::List(list_type) => self.decode_list(list_type.get_element_type()?, pointer)?,
+            type_::Struct(struct_type) => {
+                self.decode_struct(struct_type.get_type_id(), pointer.get_struct(None)?)?
+            }
+            type_::Interface(_) | type_::AnyPointer(_) => Value::Null,
+            _ => return Err(capnp::Error::failed("not a pointer type".to_string())),
+        })
+    }
+
+    fn decode_list(
+        &self,
+        element_type: type_::Reader<'_>,
+        pointer: PointerReader<'_>,
+    ) -> capnp::Result<Value> {
+        macro_rules! primitives {
+            ($ty:ty) => {
+                primitive_list::Reader::<$ty>::get_from_pointer(&pointer, None)?
+                    .iter()
+                    .map(Value::from)
+                    .collect()
+            };
+        }
+
+        let elements = match element_type.which()? {
+            type_::Void(()) => {
+                let list = pointer.get_list(ElementSize::Void, None)?;
+                vec![Value::Null; list.len() as usize]
+            }
+            type_::Bool(()) => primitives!(bool),
+            type_::Int8(()) => primitives!(i8),
+            type_::Int16(()) => primitives!(i16),
+            type_::Int32(()) => primitives!(i32),
+            type_::Int64(()) => primitives!(i64),
+            type_::Uint8(()) => primitives!(u8),
+            type_::Uint16(()) => primitives!(u16),
+            type_::Uint32(()) => primitives!(u32),
+            type_::Uint64(()) => primitives!(u64),
+            type_::Float32(()) => primitives!(f32),
+            type_::Float64(()) => primitives!(f64),
+            type_::Enum(enum_type) => {
+                primitive_list::Reader::<u16>::get_from_pointer(&pointer, None)?
+                    .iter()
+                    .map(|value| self.enumerant(enum_type.get_type_id(), value))
+                    .collect::<capnp::Result<_>>()?
+            }
+            type_::Text(()) => text_list::Reader::get_from_pointer(&pointer, None)?
+                .iter()
+                .map(|text| Ok(Value::from(text?.to_str()?)))
+                .collect::<capnp::Result<_>>()?,
+            type_::Data(()) => data_list::Reader::get_from_pointer(&pointer, None)?
+                .iter()
+                .map(|data| Ok(bytes(data?)))
+                .collect::<capnp::Result<_>>()?,
+            type_::Struct(struct_type) => {
+                let list = pointer.get_list(ElementSize::InlineComposite, None)?;
+                (0..list.len())
+                    .map(|index| {
+                        self.decode_struct(
+                            struct_type.get_type_id(),
+                            list.get_struct_element(index),
+                        )
+                    })
+                    .collect::<capnp::Result<_>>()?
+            }
+            type_::List(_) | type_::Interface(_) | type_::AnyPointer(_) => {
+                let list = pointer.get_list(ElementSize::Pointer, None)?;
+                (0..list.len())
+                    .map(|index| {
+                        let element = list.get_pointer_element(index);
+                        if element.is_null() {
+                            Ok(Value::Null)
+                        } else {
+                            self.decode_pointer(element_type, element)
+                        }
+                    })
+                    .collect::<capnp::Result<_>>()?
+            }
+        };
+        Ok(Value::Array(elements))
+    }
+
+    fn enumerant(&self, enum_id: u64, value: u16) -> capnp::Result<Value> {
+        let enumerants = match self.node(enum_id)?.which()? {
+            node::Enum(enum_node) => enum_node.get_enumerants()?,
+            _ => return Err(not_a(enum_id, "an enum")),
+        };
+        // Values added by a newer schema than the decoder's have no name.
+        if u32::from(value) < enumerants.len() {
+            let name = enumerants.get(u32::from(value)).get_name()?;
+            Ok(Value::from(name.to_str()?))
+        } else {
+            Ok(Value::from(value))
+        }
+    }
+}
+
+/// Any pointer, for the decoder to read as whatever the schema says is behind it.
+struct RawPointer<'a>(PointerReader<'a>);
+
+impl<'a> FromPointerReader<'a> for RawPointer<'a> {
+    fn get_from_pointer(
+        reader: &PointerReader<'a>,
+        _default: Option<&'a [Word]>,
+    ) -> capnp::Result<Self> {
+        Ok(RawPointer(*reader))
+    }
+}
+
+fn bytes(data: data::Reader<'_>) -> Value {
+    Value::from(data.to_vec())
+}
+
+fn not_a(node_id: u64, kind: &str) -> capnp::Error {
+    capnp::Error::failed(format!("node {:#x} is not {}", node_id, kind))
+}
@@ -0,0 +1,435 @@
+//! Builders for every `DemoInstruction`, with the accounts the program expects.
+//!
+//! Person accounts are addressed by `funding`, the wallet that initialized them and that their
+//! address is derived from (see [`person_address`]). Instructions that change a Person are signed
+//! by its `authority`, which starts out as `funding` and moves with `SetAuthority`.
+
+use borsh::BorshSerialize;
+use capnp::message::Builder;
+use capnp::serialize;
+use solana_capnp_demo::descriptor::SchemaEncoding;
+use solana_capnp_demo::instruction_capnp::demo_instruction;
+use solana_capnp_demo::schema_buffer::schema_buffer_size;
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_instruction, system_program};
+
+use crate::pda::{descriptor_address, person_address, program_data_address};
+use crate::person::Address;
+use crate::{DataFormat, MessageEncoding};
+
+fn instruction_data(build: impl FnOnce(demo_instruction::Builder)) -> Vec<u8> {
+    let mut message = Builder::new_default();
+    build(message.init_root::<demo_instruction::Builder>());
+    serialize::write_message_to_words(&message)
+}
+
+/// Accounts of the instructions that take a descriptor: `signer`, the Person of `funding`, the
+/// descriptor of `format` and the system program.
+fn descriptor_accounts(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    funding: &Pubkey,
+    format: DataFormat,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*signer, true),
+        AccountMeta::new(person_address(program_id, funding).0, false),
+        AccountMeta::new(descriptor_address(program_id, format).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]
+}
+
+/// `InitializeCapnp`: creates the capnp Person of `funding` in `encoding`. `funding` pays for the
+/// account, and for the descriptor if it's the first capnp Person, and becomes its authority.
+pub fn initialize_capnp(
+    program_id: &Pubkey,
+    funding: &Pubkey,
+    firstname: &str,
+    lastname: &str,
+    encoding: MessageEncoding,
+) -> Instruction {
+    let (_, bump) = person_address(program_id, funding);
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_initialize_capnp();
+        params.set_bump(bump);
+        params.set_packed(encoding == MessageEncoding::Packed);
+        let mut person = params.init_person();
+        person.set_firstname(firstname.into());
+        person.set_lastname(lastname.into());
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, funding, funding, DataFormat::Capnp),
+    )
+}
+
+/// `DeserializeCapnp`: logs the names of the capnp Person of `funding`.
+pub fn deserialize_capnp(program_id: &Pubkey, funding: &Pubkey) -> Instruction {
+    let data = instruction_data(|mut instruction| instruction.set_deserialize_capnp(()));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, funding, funding, DataFormat::Capnp),
+    )
+}
+
+/// `InitializeBorsh`: creates the Borsh Person of `funding`, which pays for it and becomes its
+/// authority.
+pub fn initialize_borsh(
+    program_id: &Pubkey,
+    funding: &Pubkey,
+    firstname: &str,
+    lastname: &str,
+) -> Instruction {
+    let (_, bump) = person_address(program_id, funding);
+    // The program sets the flag and the authority itself.
+    let person_state = (
+        false,
+        firstname.to_string(),
+        lastname.to_string(),
+        Pubkey::default(),
+    )
+        .try_to_vec()
+        .expect("serializing into a Vec");
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_initialize_borsh();
+        params.set_bump(bump);
+        params.set_person_state(&person_state);
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, funding, funding, DataFormat::Borsh),
+    )
+}
+
+/// `DeserializeBorsh`: logs the names of the Borsh Person of `funding`.
+pub fn deserialize_borsh(program_id: &Pubkey, funding: &Pubkey) -> Instruction {
+    let data = instruction_data(|mut instruction| instruction.set_deserialize_borsh(()));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, funding, funding, DataFormat::Borsh),
+    )
+}
+
+/// `UpdateCapnp`: replaces the names of the capnp Person of `funding`.
+pub fn update_capnp(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    funding: &Pubkey,
+    firstname: &str,
+    lastname: &str,
+) -> Instruction {
+    let (person, bump) = person_address(program_id, funding);
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_update_capnp();
+        params.set_bump(bump);
+        let mut person = params.init_person();
+        person.set_firstname(firstname.into());
+        person.set_lastname(lastname.into());
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(person, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `Close`: closes the Person of `funding`, in either format, and returns its rent to
+/// `authority`.
+pub fn close(program_id: &Pubkey, authority: &Pubkey, funding: &Pubkey) -> Instruction {
+    let (person, bump) = person_address(program_id, funding);
+    let data = instruction_data(|instruction| instruction.init_close().set_bump(bump));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(person, false),
+        ],
+    )
+}
+
+/// `CloseDescriptor`: closes the Person descriptor of `format`. Only the program's upgrade
+/// authority may sign it.
+pub fn close_descriptor(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    format: DataFormat,
+) -> Instruction {
+    let data = instruction_data(|mut instruction| instruction.set_close_descriptor(()));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*upgrade_authority, true),
+            AccountMeta::new(descriptor_address(program_id, format).0, false),
+            AccountMeta::new_readonly(program_data_address(program_id), false),
+        ],
+    )
+}
+
+/// `MigratePerson`: moves the capnp Person of `funding` to person_v2.capnp with the given v2
+/// fields.
+pub fn migrate_person(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    funding: &Pubkey,
+    email: &str,
+    birth_year: u16,
+    address: Option<&Address>,
+) -> Instruction {
+    let (_, bump) = person_address(program_id, funding);
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_migrate_person();
+        params.set_bump(bump);
+        params.set_email(email.into());
+        params.set_birth_year(birth_year);
+        if let Some(address) = address {
+            let mut builder = params.init_address();
+            builder.set_street(address.street.as_str().into());
+            builder.set_city(address.city.as_str().into());
+            builder.set_postal_code(address.postal_code.as_str().into());
+            builder.set_country(address.country.as_str().into());
+        }
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, authority, funding, DataFormat::Capnp),
+    )
+}
+
+/// `SetAuthority`: hands the Person of `funding`, in either format, off to `new_authority`.
+pub fn set_authority(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    funding: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let (person, bump) = person_address(program_id, funding);
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_set_authority();
+        params.set_bump(bump);
+        params.set_new_authority(new_authority.as_ref());
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(person, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `ConvertBorshToCapnp`: rewrites the Borsh Person of `funding` as a capnp Person in `encoding`.
+pub fn convert_borsh_to_capnp(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    funding: &Pubkey,
+    encoding: MessageEncoding,
+) -> Instruction {
+    let (_, bump) = person_address(program_id, funding);
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_convert_borsh_to_capnp();
+        params.set_bump(bump);
+        params.set_packed(encoding == MessageEncoding::Packed);
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, authority, funding, DataFormat::Capnp),
+    )
+}
+
+/// `ConvertCapnpToBorsh`: rewrites the version 1 capnp Person of `funding` as a Borsh Person.
+pub fn convert_capnp_to_borsh(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    funding: &Pubkey,
+) -> Instruction {
+    let (_, bump) = person_address(program_id, funding);
+    let data =
+        instruction_data(|instruction| instruction.init_convert_capnp_to_borsh().set_bump(bump));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        descriptor_accounts(program_id, authority, funding, DataFormat::Borsh),
+    )
+}
+
+/// Accounts of the schema upload instructions: the upgrade authority, the upload buffer, the
+/// Person descriptor of `format`, the program's `ProgramData` and the system program.
+fn schema_upload_accounts(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    buffer: &Pubkey,
+    format: DataFormat,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*upgrade_authority, true),
+        AccountMeta::new(*buffer, false),
+        AccountMeta::new(descriptor_address(program_id, format).0, false),
+        AccountMeta::new_readonly(program_data_address(program_id), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]
+}
+
+/// Creates `buffer`, a new keypair account, with room for a schema of `schema_len` bytes and
+/// the program as owner. `lamports` should be the rent exemption of
+/// [`schema_buffer_size`]`(schema_len)` bytes; it goes back to the upgrade authority at
+/// finalize.
+pub fn create_schema_buffer(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    buffer: &Pubkey,
+    schema_len: usize,
+    lamports: u64,
+) -> Instruction {
+    system_instruction::create_account(
+        payer,
+        buffer,
+        lamports,
+        schema_buffer_size(schema_len) as u64,
+        program_id,
+    )
+}
+
+/// `BeginSchemaUpload`: sets `buffer` up to receive a schema in `encoding` for the Person
+/// descriptor of `format`.
+pub fn begin_schema_upload(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    buffer: &Pubkey,
+    format: DataFormat,
+    encoding: SchemaEncoding,
+) -> Instruction {
+    let data = instruction_data(|instruction| {
+        instruction
+            .init_begin_schema_upload()
+            .set_encoding(encoding as u8)
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        schema_upload_accounts(program_id, upgrade_authority, buffer, format),
+    )
+}
+
+/// `WriteSchemaChunk`: writes `bytes` into `buffer`, `offset` bytes into the schema.
+pub fn write_schema_chunk(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    buffer: &Pubkey,
+    format: DataFormat,
+    offset: u32,
+    bytes: &[u8],
+) -> Instruction {
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_write_schema_chunk();
+        params.set_offset(offset);
+        params.set_bytes(bytes);
+    });
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        schema_upload_accounts(program_id, upgrade_authority, buffer, format),
+    )
+}
+
+/// `FinalizeSchema`: replaces the schema of the Person descriptor of `format` with the one in
+/// `buffer` if its sha256 is `hash`, and closes the buffer.
+pub fn finalize_schema(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    buffer: &Pubkey,
+    format: DataFormat,
+    hash: &[u8; 32],
+) -> Instruction {
+    let data = instruction_data(|instruction| instruction.init_finalize_schema().set_hash(hash));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        schema_upload_accounts(program_id, upgrade_authority, buffer, format),
+    )
+}
+
+/// `CancelSchemaUpload`: closes `buffer` without finalizing it and shrinks the Person descriptor
+/// of `format` back to the schema it holds.
+pub fn cancel_schema_upload(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    buffer: &Pubkey,
+    format: DataFormat,
+) -> Instruction {
+    let data = instruction_data(|mut instruction| instruction.set_cancel_schema_upload(()));
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        schema_upload_accounts(program_id, upgrade_authority, buffer, format),
+    )
+}
+
+/// Largest `chunk_len` [`upload_schema`] takes: a `WriteSchemaChunk` carrying this many bytes
+/// still fits in a transaction of its own when a separate fee payer signs it as well.
+pub const MAX_SCHEMA_CHUNK_LEN: usize = 768;
+
+/// Every instruction uploading `schema` through `buffer`, created with
+/// [`create_schema_buffer`] beforehand: `BeginSchemaUpload`, one `WriteSchemaChunk` per
+/// `chunk_len` bytes and `FinalizeSchema`. Each chunk needs a transaction of its own once
+/// `chunk_len` gets close to [`MAX_SCHEMA_CHUNK_LEN`].
+///
+/// # Panics
+///
+/// If `chunk_len` is 0 or larger than [`MAX_SCHEMA_CHUNK_LEN`].
+pub fn upload_schema(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    buffer: &Pubkey,
+    format: DataFormat,
+    encoding: SchemaEncoding,
+    schema: &[u8],
+    chunk_len: usize,
+) -> Vec<Instruction> {
+    assert!(
+        (1..=MAX_SCHEMA_CHUNK_LEN).contains(&chunk_len),
+        "chunk_len must be between 1 and {}, got {}",
+        MAX_SCHEMA_CHUNK_LEN,
+        chunk_len
+    );
+    let mut instructions = vec![begin_schema_upload(
+        program_id,
+        upgrade_authority,
+        buffer,
+        format,
+        encoding,
+    )];
+    for (index, chunk) in schema.chunks(chunk_len).enumerate() {
+        instructions.push(write_schema_chunk(
+            program_id,
+            upgrade_authority,
+            buffer,
+            format,
+            (index * chunk_len) as u32,
+            chunk,
+        ));
+    }
+    instructions.push(finalize_schema(
+        program_id,
+        upgrade_authority,
+        buffer,
+        format,
+        &hash(schema).to_bytes(),
+    ));
+    instructions
+}
@@ -0,0 +1,18 @@
+//! Off-chain Rust client of the demo program.
+//!
+//! [`instruction`] builds every `DemoInstruction` with the accounts it expects, [`pda`] derives
+//! the addresses of Person and descriptor accounts and [`person`] decodes fetched Person accounts
+//! of either format, or with [`dynamic`] as JSON, given nothing but the schema. The program itself
+//! is linked with its `no-entrypoint` feature, so its types and constants are available through
+//! [`program`].
+//!
+//! The program has no fixed address, every function takes the `program_id` it was deployed at.
+
+pub mod dynamic;
+pub mod instruction;
+pub mod pda;
+pub mod person;
+
+pub use solana_capnp_demo as program;
+pub use solana_capnp_demo::account::MessageEncoding;
+pub use solana_capnp_demo::descriptor::DataFormat;
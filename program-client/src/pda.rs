@@ -0,0 +1,27 @@
+//! Addresses of the program's accounts.
+
+use solana_capnp_demo::account::PERSON_TYPE_NAME;
+use solana_capnp_demo::capnp_account::CapnpAccount;
+use solana_capnp_demo::descriptor::{find_descriptor_address, DataFormat};
+use solana_capnp_demo::person::Person;
+use solana_program::bpf_loader_upgradeable;
+use solana_program::pubkey::Pubkey;
+
+pub use solana_capnp_demo::account::find_account_descriptor;
+
+/// Address and bump of the Person account `funding` initializes, in either format. The address
+/// stays the same when the Person changes hands or format.
+pub fn person_address(program_id: &Pubkey, funding: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&Person::seeds(funding), program_id)
+}
+
+/// Address and bump of the descriptor shared by every Person account in `format`.
+pub fn descriptor_address(program_id: &Pubkey, format: DataFormat) -> (Pubkey, u8) {
+    find_descriptor_address(program_id, format, PERSON_TYPE_NAME)
+}
+
+/// Address of the `ProgramData` account naming the program's upgrade authority, which
+/// `CloseDescriptor` and the schema upload instructions read.
+pub fn program_data_address(program_id: &Pubkey) -> Pubkey {
+    bpf_loader_upgradeable::get_program_data_address(program_id)
+}
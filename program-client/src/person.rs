@@ -0,0 +1,124 @@
+//! Decoding of fetched Person accounts.
+//!
+//! The decoders make the checks the program makes before it reads an account, apart from the
+//! owner, which is up to the caller: an account fetched from another program may look the same.
+
+use solana_capnp_demo::account::{
+    account_type, capnp_message_encoding, capnp_person_discriminator, capnp_schema_version,
+    check_account_data, read_capnp_message, unpack_borsh_person, MessageEncoding,
+    CAPNP_ACCOUNT_MIN_LEN, PERSON_V1,
+};
+use solana_capnp_demo::descriptor::DataFormat;
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::person_v2_capnp;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// A Person account of either format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Person {
+    pub format: DataFormat,
+    /// Person schema version of the account. Borsh Persons are always version 1.
+    pub version: u8,
+    /// Encoding of the message of a capnp Person, `None` for Borsh.
+    pub encoding: Option<MessageEncoding>,
+    pub firstname: String,
+    pub lastname: String,
+    /// `None` for Persons written before authorities existed.
+    pub authority: Option<Pubkey>,
+    /// What person_v2.capnp adds, for version 2 Persons.
+    pub details: Option<PersonDetails>,
+}
+
+/// The fields of a version 2 Person.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PersonDetails {
+    pub email: String,
+    pub birth_year: u16,
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+/// Decodes the data of a Person account, in whichever format its discriminator names.
+pub fn decode_person(data: &[u8]) -> Result<Person, ProgramError> {
+    let discriminator = data.get(0..8).ok_or(DemoError::AccountTooShort)?;
+    match account_type(discriminator) {
+        Some((DataFormat::Capnp, _)) => decode_capnp_person(data),
+        Some((DataFormat::Borsh, _)) => decode_borsh_person(data),
+        None => Err(DemoError::WrongDiscriminator.into()),
+    }
+}
+
+/// Decodes the data of a capnp Person account, of any schema version and encoding.
+pub fn decode_capnp_person(data: &[u8]) -> Result<Person, ProgramError> {
+    check_account_data(data, &capnp_person_discriminator(), CAPNP_ACCOUNT_MIN_LEN)?;
+    let version = capnp_schema_version(data);
+    let encoding = capnp_message_encoding(data)?;
+
+    // person_v2.capnp reads v1 messages as well, the fields v2 adds are only used at v2.
+    let message = read_capnp_message(data)?;
+    let person = message.root::<person_v2_capnp::person::Owned>()?;
+    let details = if version > PERSON_V1 {
+        let address = if person.has_address() {
+            let address = person.get_address().map_err(|_| DemoError::CapnpDecode)?;
+            Some(Address {
+                street: text(address.get_street())?,
+                city: text(address.get_city())?,
+                postal_code: text(address.get_postal_code())?,
+                country: text(address.get_country())?,
+            })
+        } else {
+            None
+        };
+        Some(PersonDetails {
+            email: text(person.get_email())?,
+            birth_year: person.get_birth_year(),
+            address,
+        })
+    } else {
+        None
+    };
+
+    let authority = if person.has_authority() {
+        let authority = person.get_authority().map_err(|_| DemoError::CapnpDecode)?;
+        Some(Pubkey::try_from(authority).map_err(|_| DemoError::CapnpDecode)?)
+    } else {
+        None
+    };
+
+    Ok(Person {
+        format: DataFormat::Capnp,
+        version,
+        encoding: Some(encoding),
+        firstname: text(person.get_firstname())?,
+        lastname: text(person.get_lastname())?,
+        authority,
+        details,
+    })
+}
+
+/// Decodes the data of a Borsh Person account.
+pub fn decode_borsh_person(data: &[u8]) -> Result<Person, ProgramError> {
+    let person_state = unpack_borsh_person(data)?;
+    Ok(Person {
+        format: DataFormat::Borsh,
+        version: PERSON_V1,
+        encoding: None,
+        firstname: person_state.first_name().to_string(),
+        lastname: person_state.last_name().to_string(),
+        authority: person_state.authority(),
+        details: None,
+    })
+}
+
+fn text(text: capnp::Result<capnp::text::Reader>) -> Result<String, ProgramError> {
+    text.and_then(|text| Ok(text.to_str()?.to_string()))
+        .map_err(|_| DemoError::CapnpDecode.into())
+}
@@ -0,0 +1,395 @@
+use solana_capnp_demo::descriptor::{read_descriptor, SchemaEncoding};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::instruction as process_instruction;
+use solana_capnp_demo::schema_buffer::schema_buffer_size;
+use solana_capnp_demo_client::instruction::{self, MAX_SCHEMA_CHUNK_LEN};
+use solana_capnp_demo_client::pda::{
+    descriptor_address, find_account_descriptor, person_address, program_data_address,
+};
+use solana_capnp_demo_client::person::{decode_person, Address, Person, PersonDetails};
+use solana_capnp_demo_client::{DataFormat, MessageEncoding};
+use solana_program::bpf_loader_upgradeable;
+use solana_program::hash::Hash;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+struct Demo {
+    banks_client: BanksClient,
+    payer: Keypair,
+    program_id: Pubkey,
+}
+
+impl Demo {
+    /// Starts the program with funded `wallets` and a `ProgramData` account naming
+    /// `upgrade_authority`.
+    async fn start(wallets: &[&Keypair], upgrade_authority: &Pubkey) -> Demo {
+        let program_id = Pubkey::new_unique();
+        let mut program_test = ProgramTest::new(
+            "solana_capnp_demo",
+            program_id,
+            processor!(process_instruction),
+        );
+        for wallet in wallets {
+            program_test.add_account(
+                wallet.pubkey(),
+                Account::new(10_000_000_000, 0, &system_program::id()),
+            );
+        }
+        // UpgradeableLoaderState::ProgramData: [tag u32 = 3][slot u64][Option<Pubkey>]
+        let mut program_data = vec![3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        program_data.extend(upgrade_authority.to_bytes());
+        program_test.add_account(
+            program_data_address(&program_id),
+            Account {
+                lamports: 1_000_000_000,
+                data: program_data,
+                owner: bpf_loader_upgradeable::id(),
+                ..Account::default()
+            },
+        );
+
+        let (banks_client, payer, _) = program_test.start().await;
+        Demo {
+            banks_client,
+            payer,
+            program_id,
+        }
+    }
+
+    async fn send(
+        &mut self,
+        instruction: Instruction,
+        signer: &Keypair,
+    ) -> Result<(), ProgramError> {
+        let recent_blockhash = self.banks_client.get_latest_blockhash().await.unwrap();
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, signer],
+            recent_blockhash,
+        );
+        match self.banks_client.process_transaction(transaction).await {
+            Ok(()) => Ok(()),
+            Err(error) => match error.unwrap() {
+                TransactionError::InstructionError(_, error) => {
+                    Err(ProgramError::try_from(error).unwrap())
+                }
+                error => panic!("{:?}", error),
+            },
+        }
+    }
+
+    async fn person(&mut self, funding: &Keypair) -> Option<Person> {
+        let (address, _) = person_address(&self.program_id, &funding.pubkey());
+        let account = self.banks_client.get_account(address).await.unwrap()?;
+        Some(decode_person(&account.data).unwrap())
+    }
+}
+
+#[tokio::test]
+async fn capnp_person_lifecycle() {
+    let (funding, new_authority) = (Keypair::new(), Keypair::new());
+    let mut demo = Demo::start(&[&funding, &new_authority], &Pubkey::new_unique()).await;
+    let program_id = demo.program_id;
+    let funding_key = funding.pubkey();
+
+    let initialize = instruction::initialize_capnp(
+        &program_id,
+        &funding_key,
+        "Captain",
+        "Proto",
+        MessageEncoding::Packed,
+    );
+    demo.send(initialize, &funding).await.unwrap();
+    demo.send(
+        instruction::deserialize_capnp(&program_id, &funding_key),
+        &funding,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        demo.person(&funding).await.unwrap(),
+        Person {
+            format: DataFormat::Capnp,
+            version: 1,
+            encoding: Some(MessageEncoding::Packed),
+            firstname: "Captain".to_string(),
+            lastname: "Proto".to_string(),
+            authority: Some(funding_key),
+            details: None,
+        }
+    );
+
+    let update = instruction::update_capnp(
+        &program_id,
+        &funding_key,
+        &funding_key,
+        "Commander",
+        "Proto",
+    );
+    demo.send(update, &funding).await.unwrap();
+    let address = Address {
+        city: "Wordsworth".to_string(),
+        country: "Protoland".to_string(),
+        ..Address::default()
+    };
+    let migrate = instruction::migrate_person(
+        &program_id,
+        &funding_key,
+        &funding_key,
+        "captain@proto.dev",
+        1987,
+        Some(&address),
+    );
+    demo.send(migrate, &funding).await.unwrap();
+
+    let person = demo.person(&funding).await.unwrap();
+    assert_eq!(person.version, 2);
+    assert_eq!(person.firstname, "Commander");
+    assert_eq!(
+        person.details,
+        Some(PersonDetails {
+            email: "captain@proto.dev".to_string(),
+            birth_year: 1987,
+            address: Some(address),
+        })
+    );
+
+    let hand_off = instruction::set_authority(
+        &program_id,
+        &funding_key,
+        &funding_key,
+        &new_authority.pubkey(),
+    );
+    demo.send(hand_off, &funding).await.unwrap();
+    assert_eq!(
+        demo.person(&funding).await.unwrap().authority,
+        Some(new_authority.pubkey())
+    );
+
+    let close = instruction::close(&program_id, &funding_key, &funding_key);
+    assert_eq!(
+        demo.send(close, &funding).await,
+        Err(DemoError::InvalidAuthority.into())
+    );
+    let close = instruction::close(&program_id, &new_authority.pubkey(), &funding_key);
+    demo.send(close, &new_authority).await.unwrap();
+    assert_eq!(demo.person(&funding).await, None);
+}
+
+#[tokio::test]
+async fn borsh_person_converts_both_ways() {
+    let funding = Keypair::new();
+    let mut demo = Demo::start(&[&funding], &Pubkey::new_unique()).await;
+    let program_id = demo.program_id;
+    let funding_key = funding.pubkey();
+
+    let initialize = instruction::initialize_borsh(&program_id, &funding_key, "John", "Borsh");
+    demo.send(initialize, &funding).await.unwrap();
+    demo.send(
+        instruction::deserialize_borsh(&program_id, &funding_key),
+        &funding,
+    )
+    .await
+    .unwrap();
+    let borsh = demo.person(&funding).await.unwrap();
+    assert_eq!(
+        borsh,
+        Person {
+            format: DataFormat::Borsh,
+            version: 1,
+            encoding: None,
+            firstname: "John".to_string(),
+            lastname: "Borsh".to_string(),
+            authority: Some(funding_key),
+            details: None,
+        }
+    );
+
+    let convert = instruction::convert_borsh_to_capnp(
+        &program_id,
+        &funding_key,
+        &funding_key,
+        MessageEncoding::Unpacked,
+    );
+    demo.send(convert, &funding).await.unwrap();
+    let capnp = demo.person(&funding).await.unwrap();
+    assert_eq!(capnp.format, DataFormat::Capnp);
+    assert_eq!(capnp.encoding, Some(MessageEncoding::Unpacked));
+    assert_eq!(
+        (&capnp.firstname, capnp.authority),
+        (&borsh.firstname, borsh.authority)
+    );
+
+    // The Person's discriminator leads to the descriptor holding its schema.
+    let (address, _) = person_address(&program_id, &funding_key);
+    let account = demo
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        find_account_descriptor(&program_id, &account.data).unwrap(),
+        descriptor_address(&program_id, DataFormat::Capnp)
+    );
+
+    let convert = instruction::convert_capnp_to_borsh(&program_id, &funding_key, &funding_key);
+    demo.send(convert, &funding).await.unwrap();
+    assert_eq!(demo.person(&funding).await.unwrap(), borsh);
+}
+
+#[tokio::test]
+async fn close_descriptor_is_signed_by_the_upgrade_authority() {
+    let (funding, upgrade_authority) = (Keypair::new(), Keypair::new());
+    let mut demo = Demo::start(&[&funding, &upgrade_authority], &upgrade_authority.pubkey()).await;
+    let program_id = demo.program_id;
+
+    let initialize = instruction::initialize_borsh(&program_id, &funding.pubkey(), "John", "Borsh");
+    demo.send(initialize, &funding).await.unwrap();
+
+    let close = instruction::close_descriptor(&program_id, &funding.pubkey(), DataFormat::Borsh);
+    assert_eq!(
+        demo.send(close, &funding).await,
+        Err(ProgramError::IllegalOwner)
+    );
+    let close =
+        instruction::close_descriptor(&program_id, &upgrade_authority.pubkey(), DataFormat::Borsh);
+    demo.send(close, &upgrade_authority).await.unwrap();
+
+    let (descriptor, _) = descriptor_address(&program_id, DataFormat::Borsh);
+    assert_eq!(
+        demo.banks_client.get_account(descriptor).await.unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn schema_upload_publishes_a_schema_in_chunks() {
+    let (funding, upgrade_authority) = (Keypair::new(), Keypair::new());
+    let mut demo = Demo::start(&[&funding, &upgrade_authority], &upgrade_authority.pubkey()).await;
+    let program_id = demo.program_id;
+    let initialize = instruction::initialize_capnp(
+        &program_id,
+        &funding.pubkey(),
+        "Captain",
+        "Proto",
+        MessageEncoding::Unpacked,
+    );
+    demo.send(initialize, &funding).await.unwrap();
+
+    let schema = capnp_models::PERSON_SCHEMAS[1].as_bytes();
+    let buffer = Keypair::new();
+    let rent = demo.banks_client.get_rent().await.unwrap();
+    let create = instruction::create_schema_buffer(
+        &program_id,
+        &demo.payer.pubkey(),
+        &buffer.pubkey(),
+        schema.len(),
+        rent.minimum_balance(schema_buffer_size(schema.len())),
+    );
+    demo.send(create, &buffer).await.unwrap();
+    let upload = instruction::upload_schema(
+        &program_id,
+        &upgrade_authority.pubkey(),
+        &buffer.pubkey(),
+        DataFormat::Capnp,
+        SchemaEncoding::CapnpText,
+        schema,
+        500,
+    );
+    assert_eq!(upload.len(), 2 + schema.chunks(500).count());
+    for instruction in upload {
+        demo.send(instruction, &upgrade_authority).await.unwrap();
+    }
+
+    let (descriptor, _) = descriptor_address(&program_id, DataFormat::Capnp);
+    let descriptor = demo.banks_client.get_account(descriptor).await.unwrap();
+    let (header, payload) = read_descriptor(&descriptor.as_ref().unwrap().data).unwrap();
+    assert_eq!(header.encoding, SchemaEncoding::CapnpText);
+    assert_eq!(payload, schema);
+    assert_eq!(
+        demo.banks_client
+            .get_account(buffer.pubkey())
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn decode_person_checks_the_account() {
+    assert_eq!(
+        decode_person(&[0; 4]),
+        Err(DemoError::AccountTooShort.into())
+    );
+    assert_eq!(
+        decode_person(&[1; 64]),
+        Err(DemoError::WrongDiscriminator.into())
+    );
+
+    let mut data = vec![0; 64];
+    data[0..8].copy_from_slice(&solana_capnp_demo::account::capnp_person_discriminator());
+    assert_eq!(decode_person(&data), Err(DemoError::Uninitialized.into()));
+}
+
+#[test]
+fn largest_schema_chunk_fits_a_transaction() {
+    let (program_id, upgrade_authority, buffer) =
+        (Pubkey::new_unique(), Keypair::new(), Pubkey::new_unique());
+    let payer = Keypair::new();
+    let upload = instruction::upload_schema(
+        &program_id,
+        &upgrade_authority.pubkey(),
+        &buffer,
+        DataFormat::Capnp,
+        SchemaEncoding::CapnpText,
+        &[7; MAX_SCHEMA_CHUNK_LEN],
+        MAX_SCHEMA_CHUNK_LEN,
+    );
+    assert_eq!(upload.len(), 3);
+    let transaction = Transaction::new_signed_with_payer(
+        &upload[1..2],
+        Some(&payer.pubkey()),
+        &[&payer, &upgrade_authority],
+        Hash::default(),
+    );
+    // The signatures behind their compact length, then the message.
+    let size = 1 + 64 * transaction.signatures.len() + transaction.message.serialize().len();
+    assert!(size <= PACKET_DATA_SIZE, "{} bytes", size);
+}
+
+#[test]
+#[should_panic(expected = "chunk_len must be between 1 and")]
+fn upload_schema_rejects_empty_chunks() {
+    instruction::upload_schema(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        DataFormat::Capnp,
+        SchemaEncoding::CapnpText,
+        b"struct Person {}",
+        0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "chunk_len must be between 1 and")]
+fn upload_schema_rejects_chunks_too_large_for_a_transaction() {
+    instruction::upload_schema(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        DataFormat::Capnp,
+        SchemaEncoding::CapnpText,
+        b"struct Person {}",
+        MAX_SCHEMA_CHUNK_LEN + 1,
+    );
+}
@@ -0,0 +1,240 @@
+use std::process::Command;
+
+use capnp::message::{Builder, ReaderOptions};
+use capnp::serialize;
+use serde_json::json;
+use solana_capnp_demo::instruction as process_instruction;
+use solana_capnp_demo_client::dynamic::{schema_capnp, SchemaDecoder};
+use solana_capnp_demo_client::instruction;
+use solana_capnp_demo_client::pda::{find_account_descriptor, person_address};
+use solana_capnp_demo_client::person::Address;
+use solana_capnp_demo_client::program::descriptor::read_descriptor;
+use solana_capnp_demo_client::MessageEncoding;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+/// Compiles `schema`, saved as `file_name`, into the binary `CodeGeneratorRequest` the capnp
+/// compiler hands its plugins.
+fn code_generator_request(file_name: &str, schema: &[u8]) -> Vec<u8> {
+    let dir = std::env::temp_dir().join(format!("dynamic_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(file_name), schema).unwrap();
+    let output = Command::new("capnp")
+        .current_dir(&dir)
+        .args(["compile", "-o-", file_name])
+        .output()
+        .expect("running capnp");
+    assert!(output.status.success(), "{:?}", output);
+    output.stdout
+}
+
+async fn send(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    instruction: Instruction,
+    signer: &Keypair,
+) {
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    banks_client
+        .process_transaction(Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, signer],
+            recent_blockhash,
+        ))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn decodes_a_person_with_the_schema_of_its_descriptor() {
+    let program_id = Pubkey::new_unique();
+    let funding = Keypair::new();
+    let funding_key = funding.pubkey();
+    let mut program_test = ProgramTest::new(
+        "solana_capnp_demo",
+        program_id,
+        processor!(process_instruction),
+    );
+    program_test.add_account(
+        funding_key,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    let (mut banks_client, payer, _) = program_test.start().await;
+
+    let initialize = instruction::initialize_capnp(
+        &program_id,
+        &funding_key,
+        "Captain",
+        "Proto",
+        MessageEncoding::Packed,
+    );
+    send(&mut banks_client, &payer, initialize, &funding).await;
+    let address = Address {
+        street: "1 Packed Lane".to_string(),
+        city: "Wordsworth".to_string(),
+        postal_code: "1987".to_string(),
+        country: "Protoland".to_string(),
+    };
+    let migrate = instruction::migrate_person(
+        &program_id,
+        &funding_key,
+        &funding_key,
+        "captain@proto.dev",
+        1987,
+        Some(&address),
+    );
+    send(&mut banks_client, &payer, migrate, &funding).await;
+
+    // Everything comes from the chain: the Person, and the schema in the descriptor its
+    // discriminator names.
+    let (person, _) = person_address(&program_id, &funding_key);
+    let person = banks_client.get_account(person).await.unwrap().unwrap();
+    let (descriptor, _) = find_account_descriptor(&program_id, &person.data).unwrap();
+    let descriptor = banks_client.get_account(descriptor).await.unwrap().unwrap();
+    let (_, schema) = read_descriptor(&descriptor.data).unwrap();
+
+    // The descriptor holds the compiled schema, no capnp compiler needed.
+    let request = serialize::read_message(schema, ReaderOptions::new()).unwrap();
+    let decoder = SchemaDecoder::new(request.get_root().unwrap()).unwrap();
+    let person_id = decoder.find_struct("Person").expect("Person struct");
+    assert_eq!(
+        decoder.decode_account(person_id, &person.data).unwrap(),
+        json!({
+            "firstname": "Captain",
+            "lastname": "Proto",
+            "email": "captain@proto.dev",
+            "birthYear": 1987,
+            "address": {
+                "street": "1 Packed Lane",
+                "city": "Wordsworth",
+                "postalCode": "1987",
+                "country": "Protoland",
+            },
+            "authority": funding_key.to_bytes().to_vec(),
+        })
+    );
+
+    let error = decoder
+        .decode_account(person_id, &person.data[..8])
+        .unwrap_err();
+    assert!(error.to_string().contains("at least"), "{}", error);
+}
+
+#[test]
+fn decodes_unions_groups_enums_and_lists() {
+    // A CodeGeneratorRequest has all of them, and carries its own schema when the compiled file
+    // imports schema.capnp, as person_v2.capnp does.
+    let bytes = code_generator_request(
+        "person_v2.capnp",
+        capnp_models::PERSON_SCHEMAS[1].as_bytes(),
+    );
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let request = message
+        .get_root::<schema_capnp::code_generator_request::Reader>()
+        .unwrap();
+    let decoder = SchemaDecoder::new(request).unwrap();
+    let request_id = decoder.find_struct("CodeGeneratorRequest").unwrap();
+    let decoded = decoder.decode_message(request_id, &message).unwrap();
+
+    let nodes = decoded["nodes"].as_array().unwrap();
+    assert_eq!(nodes.len() as u32, request.get_nodes().unwrap().len());
+    let person = nodes
+        .iter()
+        .find(|node| node["displayName"] == "person_v2.capnp:Person")
+        .expect("Person node");
+    // Node's anonymous union only has its active member, the `struct` group.
+    assert!(person.get("file").is_none());
+    assert!(person.get("enum").is_none());
+    let person_struct = &person["struct"];
+    assert_eq!(person_struct["isGroup"], false);
+    assert_eq!(person_struct["discriminantCount"], 0);
+    assert_eq!(person_struct["preferredListEncoding"], "inlineComposite");
+
+    let fields = person_struct["fields"].as_array().unwrap();
+    let names: Vec<&str> = fields
+        .iter()
+        .map(|field| field["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "firstname",
+            "lastname",
+            "email",
+            "birthYear",
+            "address",
+            "authority"
+        ]
+    );
+    assert_eq!(fields[5]["discriminantValue"], 0xffff);
+    assert_eq!(fields[5]["slot"]["type"], json!({ "data": null }));
+    assert_eq!(fields[3]["slot"]["type"], json!({ "uint16": null }));
+    assert_eq!(fields[3]["slot"]["defaultValue"], json!({ "uint16": 0 }));
+}
+
+#[test]
+fn reads_defaults_where_the_message_has_nothing() {
+    let schema = br#"@0xe6f94f52f7f6d8a1;
+
+struct Defaults {
+  count @0 :Int32 = -7;
+  ratio @1 :Float64 = 0.5;
+  enabled @2 :Bool = true;
+  color @3 :Color = blue;
+  name @4 :Text = "Captain";
+  tags @5 :List(Text) = ["a", "b"];
+  inner @6 :Inner = (label = "default", values = [1, 2]);
+  plain @7 :Text;
+  union {
+    none @8 :Void;
+    some @9 :UInt8;
+  }
+}
+
+enum Color {
+  red @0;
+  green @1;
+  blue @2;
+}
+
+struct Inner {
+  label @0 :Text;
+  values @1 :List(UInt16);
+}
+"#;
+    let bytes = code_generator_request("defaults.capnp", schema);
+    let request = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let decoder = SchemaDecoder::new(request.get_root().unwrap()).unwrap();
+
+    // A message whose root pointer is null reads as a struct with every field at its default.
+    let mut message = Builder::new_default();
+    message.get_root::<capnp::any_pointer::Builder>().unwrap();
+    let message = message.into_reader();
+    let decoded = decoder
+        .decode_message(decoder.find_struct("Defaults").unwrap(), &message)
+        .unwrap();
+    assert_eq!(
+        decoded,
+        json!({
+            "count": -7,
+            "ratio": 0.5,
+            "enabled": true,
+            "color": "blue",
+            "name": "Captain",
+            "tags": ["a", "b"],
+            "inner": { "label": "default", "values": [1, 2] },
+            "plain": null,
+            "none": null,
+        })
+    );
+
+    assert!(decoder.find_struct("Color").is_none());
+    let error = decoder.decode_message(0x1234, &message).unwrap_err();
+    assert!(error.to_string().contains("no node 0x1234"), "{}", error);
+}
@@ -0,0 +1,261 @@
+//! Loading of Person accounts.
+//!
+//! Every instruction that decodes a Person account goes through this module first, so owner,
+//! discriminator, initialized flag and length are checked the same way for both formats.
+//!
+//! Both formats keep the initialized flag right after the discriminator. For Borsh accounts that
+//! is the `is_initialized` field of [`PersonState`]; capnp accounts reserve an 8 byte header for
+//! it, which also keeps the message that follows word aligned. The byte after the flag records
+//! which version of the Person schema the message was written with, the next one whether the
+//! message is packed.
+//!
+//! ```text
+//! capnp: [discriminator: 8][initialized: 1][schema version: 1][encoding: 1][reserved: 5][Person message]
+//! borsh: [discriminator: 8][is_initialized: 1][first_name][last_name][authority: 32]
+//! ```
+//!
+//! Every [`CapnpAccount`](crate::capnp_account::CapnpAccount) type shares the capnp layout, with
+//! its own discriminator and root struct.
+//!
+//! The discriminator also says which descriptor holds an account's schema, see
+//! [`find_account_descriptor`].
+
+use std::cell::Ref;
+
+use capnp::message::{Allocator, Builder, Reader, ReaderOptions};
+use capnp::serialize::{self, NoAllocSliceSegments, OwnedSegments};
+use capnp::serialize_packed;
+use capnp::traits::Owned;
+use capnp_models::{CapnpSchema, DerivedReader};
+
+use bryte_descriptor_state::discriminators::custom_discriminator;
+use bryte_descriptor_state::states::{DescriptorDeserialize, Discriminator};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::descriptor::{find_descriptor_address, DataFormat};
+use crate::error::DemoError;
+use crate::PersonState;
+
+/// Offset of the initialized flag in both capnp and Borsh Person accounts.
+pub const INITIALIZED_OFFSET: usize = 8;
+
+/// Offset of the schema version in capnp accounts.
+pub const SCHEMA_VERSION_OFFSET: usize = 9;
+
+/// Schema version of Person messages written with person.capnp.
+pub const PERSON_V1: u8 = 1;
+
+/// Schema version of Person messages written with person_v2.capnp.
+pub const PERSON_V2: u8 = 2;
+
+/// Offset of the [`MessageEncoding`] in capnp accounts.
+pub const MESSAGE_ENCODING_OFFSET: usize = 10;
+
+/// Bytes in front of the message in a capnp account.
+pub const CAPNP_HEADER_LEN: usize = 16;
+
+/// Type name Person descriptors are published under, in both formats.
+pub const PERSON_TYPE_NAME: &str = "person";
+
+/// Smallest capnp account: the header plus a segment table and a root pointer.
+pub const CAPNP_ACCOUNT_MIN_LEN: usize = CAPNP_HEADER_LEN + 16;
+
+/// Smallest Borsh Person account: the discriminator, the flag, two empty strings and the
+/// authority.
+pub const BORSH_PERSON_MIN_LEN: usize = 8 + 1 + 4 + 4 + 32;
+
+/// Size the program gives Borsh Person accounts, whatever their names.
+pub const BORSH_PERSON_ACCOUNT_SIZE: usize = 500;
+
+/// Discriminator of capnp Person accounts.
+pub fn capnp_person_discriminator() -> [u8; 8] {
+    custom_discriminator("account".to_string(), "person".to_string())
+}
+
+/// Checks that `account` is owned by the program, is at least `min_len` bytes long, starts with
+/// `discriminator` and is initialized, then returns its data.
+pub fn load_account<'a, 'b>(
+    program_id: &Pubkey,
+    account: &'b AccountInfo<'a>,
+    discriminator: &[u8],
+    min_len: usize,
+) -> Result<Ref<'b, &'a mut [u8]>, ProgramError> {
+    if account.owner.ne(program_id) {
+        msg!("{} is not owned by the program", account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let data = account.data.borrow();
+    check_account_data(&data, discriminator, min_len)?;
+    Ok(data)
+}
+
+/// The checks of [`load_account`] that only need the account data: at least `min_len` bytes,
+/// `discriminator` first, then the initialized flag. Off-chain code checks fetched accounts with
+/// it.
+pub fn check_account_data(data: &[u8], discriminator: &[u8], min_len: usize) -> ProgramResult {
+    if data.len() < min_len.max(INITIALIZED_OFFSET + 1) {
+        return Err(DemoError::AccountTooShort.into());
+    }
+    if &data[0..8] != discriminator {
+        return Err(DemoError::WrongDiscriminator.into());
+    }
+    if data[INITIALIZED_OFFSET] != 1 {
+        return Err(DemoError::Uninitialized.into());
+    }
+    Ok(())
+}
+
+/// Loads a capnp Person account. The message starts at [`CAPNP_HEADER_LEN`].
+pub fn load_capnp_person<'a, 'b>(
+    program_id: &Pubkey,
+    account: &'b AccountInfo<'a>,
+) -> Result<Ref<'b, &'a mut [u8]>, ProgramError> {
+    load_account(
+        program_id,
+        account,
+        &capnp_person_discriminator(),
+        CAPNP_ACCOUNT_MIN_LEN,
+    )
+}
+
+/// Returns the schema version of the capnp account whose data is `data`. Accounts written before
+/// the version byte existed hold 0 there and are version 1.
+pub fn capnp_schema_version(data: &[u8]) -> u8 {
+    match data[SCHEMA_VERSION_OFFSET] {
+        0 => 1,
+        version => version,
+    }
+}
+
+/// How the message of a capnp account is serialized.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageEncoding {
+    /// The standard encoding, read in place.
+    Unpacked = 0,
+    /// The packed encoding. Smaller, but unpacked onto the heap every time it is read.
+    Packed = 1,
+}
+
+impl TryFrom<u8> for MessageEncoding {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MessageEncoding::Unpacked),
+            1 => Ok(MessageEncoding::Packed),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Returns the encoding of the capnp account whose data is `data`.
+pub fn capnp_message_encoding(data: &[u8]) -> Result<MessageEncoding, ProgramError> {
+    MessageEncoding::try_from(data[MESSAGE_ENCODING_OFFSET])
+}
+
+/// The message read out of a capnp account.
+pub enum CapnpMessage<'a> {
+    Unpacked(Reader<NoAllocSliceSegments<'a>>),
+    Packed(Reader<OwnedSegments>),
+}
+
+impl<'a> CapnpMessage<'a> {
+    pub fn get_root<'b, T: capnp::traits::FromPointerReader<'b>>(&'b self) -> capnp::Result<T> {
+        match self {
+            CapnpMessage::Unpacked(reader) => reader.get_root(),
+            CapnpMessage::Packed(reader) => reader.get_root(),
+        }
+    }
+
+    /// The root struct, read as the generated type `O`, e.g. `person_capnp::person::Owned`. Any
+    /// schema version of a type reads through any other, fields one side lacks read as defaults.
+    pub fn root<O: Owned>(&self) -> Result<O::Reader<'_>, ProgramError> {
+        self.get_root().map_err(|_| DemoError::CapnpDecode.into())
+    }
+
+    /// The root struct, read into the `#[derive(CapnpSchema)]` struct `R`.
+    pub fn read<R: CapnpSchema>(&self) -> Result<R, ProgramError> {
+        self.get_root::<DerivedReader<R>>()
+            .and_then(R::from_reader)
+            .map_err(|_| DemoError::CapnpDecode.into())
+    }
+}
+
+/// Reads the message of a capnp account, in whichever encoding the account header names.
+pub fn read_capnp_message(data: &[u8]) -> Result<CapnpMessage<'_>, ProgramError> {
+    let encoding = capnp_message_encoding(data)?;
+    let mut message = &data[CAPNP_HEADER_LEN..];
+    match encoding {
+        MessageEncoding::Unpacked => {
+            serialize::read_message_from_flat_slice_no_alloc(&mut message, ReaderOptions::new())
+                .map(CapnpMessage::Unpacked)
+        }
+        MessageEncoding::Packed => {
+            serialize_packed::read_message(message, ReaderOptions::new()).map(CapnpMessage::Packed)
+        }
+    }
+    .map_err(|_| DemoError::CapnpDecode.into())
+}
+
+/// Serializes a message the way it is stored in a capnp account with `encoding`.
+pub fn encode_capnp_message<A: Allocator>(
+    message: &Builder<A>,
+    encoding: MessageEncoding,
+) -> Result<Vec<u8>, ProgramError> {
+    let mut bytes = Vec::new();
+    match encoding {
+        MessageEncoding::Unpacked => serialize::write_message(&mut bytes, message),
+        MessageEncoding::Packed => serialize_packed::write_message(&mut bytes, message),
+    }
+    .map_err(|_| DemoError::SizeOverflow)?;
+    Ok(bytes)
+}
+
+/// Loads and decodes a Borsh Person account.
+pub fn load_borsh_person(
+    program_id: &Pubkey,
+    account: &AccountInfo,
+) -> Result<PersonState, ProgramError> {
+    let data = load_account(
+        program_id,
+        account,
+        &PersonState::DISCRIMINATOR,
+        BORSH_PERSON_MIN_LEN,
+    )?;
+
+    // The discriminator was checked above.
+    PersonState::try_deserialize_unchecked(&mut &data[..]).map_err(|_| DemoError::Borsh.into())
+}
+
+/// Decodes the data of a Borsh Person account, after the checks [`load_borsh_person`] makes of
+/// everything but the owner.
+pub fn unpack_borsh_person(data: &[u8]) -> Result<PersonState, ProgramError> {
+    check_account_data(data, &PersonState::DISCRIMINATOR, BORSH_PERSON_MIN_LEN)?;
+    PersonState::try_deserialize_unchecked(&mut &data[..]).map_err(|_| DemoError::Borsh.into())
+}
+
+/// Returns the format and type name of the account type starting with `discriminator`.
+pub fn account_type(discriminator: &[u8]) -> Option<(DataFormat, &'static str)> {
+    if discriminator == capnp_person_discriminator() {
+        Some((DataFormat::Capnp, PERSON_TYPE_NAME))
+    } else if discriminator == PersonState::DISCRIMINATOR {
+        Some((DataFormat::Borsh, PERSON_TYPE_NAME))
+    } else {
+        None
+    }
+}
+
+/// Derives the descriptor holding the schema of the account whose data is `data`.
+pub fn find_account_descriptor(
+    program_id: &Pubkey,
+    data: &[u8],
+) -> Result<(Pubkey, u8), ProgramError> {
+    let discriminator = data.get(0..8).ok_or(DemoError::AccountTooShort)?;
+    let (format, type_name) = account_type(discriminator).ok_or(DemoError::WrongDiscriminator)?;
+    Ok(find_descriptor_address(program_id, format, type_name))
+}
@@ -0,0 +1,399 @@
+//! Accounts holding a capnp message, generic over the generated struct at the message root.
+//!
+//! A type implements [`CapnpAccount`] to name its discriminator, the schemas its descriptor may
+//! hold, how its address is derived and how its accounts are sized. [`create`], [`close`],
+//! [`CapnpAccountData::load`] and [`CapnpBuilder::store`] then work for it without further code.
+
+use std::cell::Ref;
+use std::marker::PhantomData;
+
+use bryte_descriptor_state::discriminators::custom_discriminator;
+use capnp::message::{Builder, HeapAllocator};
+use capnp::traits::Owned;
+use capnp_models::CapnpSchema;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+    sysvar::Sysvar,
+};
+
+use crate::account::{
+    capnp_message_encoding, capnp_schema_version, encode_capnp_message, load_account,
+    read_capnp_message, CapnpMessage, MessageEncoding, CAPNP_ACCOUNT_MIN_LEN, CAPNP_HEADER_LEN,
+    INITIALIZED_OFFSET, MESSAGE_ENCODING_OFFSET, SCHEMA_VERSION_OFFSET,
+};
+use crate::descriptor::{
+    ensure_descriptor, find_descriptor_address, read_descriptor, DataFormat, SchemaEncoding,
+    DESCRIPTOR_SEED,
+};
+use crate::error::DemoError;
+use crate::{close_account, resize_account, SizePolicy};
+
+/// An account type stored as a capnp message.
+pub trait CapnpAccount {
+    /// Generated type of the struct at the message root, e.g. `person_capnp::person::Owned`.
+    type Owned: Owned;
+
+    /// Name of the type in its discriminator and descriptor address.
+    const TYPE_NAME: &'static str;
+
+    /// Schemas the type's descriptor may hold, in `SCHEMA_ENCODING`. [`create`] publishes the
+    /// first one if there is no descriptor yet.
+    const SCHEMAS: &'static [&'static [u8]];
+
+    /// How `SCHEMAS` are encoded: `.capnp` text unless the type publishes compiled schemas.
+    const SCHEMA_ENCODING: SchemaEncoding = SchemaEncoding::CapnpText;
+
+    /// Schemas descriptors published before `SCHEMAS` may still hold, with their encoding. They
+    /// are accepted in existing descriptors but never written.
+    const LEGACY_SCHEMAS: &'static [(SchemaEncoding, &'static [u8])] = &[];
+
+    /// Schema version [`create`] and [`CapnpBuilder::new`] write.
+    const SCHEMA_VERSION: u8 = 1;
+
+    /// Newest schema version accounts of the type may have.
+    const LATEST_SCHEMA_VERSION: u8 = Self::SCHEMA_VERSION;
+
+    const SIZE_POLICY: SizePolicy = SizePolicy::RoundUp(64);
+
+    /// Seeds of the address of the account `funding_account` creates, without the bump.
+    fn seeds(funding_account: &Pubkey) -> Vec<&[u8]>;
+
+    fn discriminator() -> [u8; 8] {
+        custom_discriminator("account".to_string(), Self::TYPE_NAME.to_string())
+    }
+
+    /// Address and bump of the type's descriptor.
+    fn descriptor_address(program_id: &Pubkey) -> (Pubkey, u8) {
+        find_descriptor_address(program_id, DataFormat::Capnp, Self::TYPE_NAME)
+    }
+}
+
+/// The data of a `T` account whose owner, discriminator, initialized flag, length and header have
+/// been checked.
+pub struct CapnpAccountData<'a, T> {
+    data: Ref<'a, [u8]>,
+    version: u8,
+    encoding: MessageEncoding,
+    account_type: PhantomData<T>,
+}
+
+impl<'a, T: CapnpAccount> CapnpAccountData<'a, T> {
+    /// Checks `account` and borrows its data for as long as the `CapnpAccountData` lives.
+    pub fn load(program_id: &Pubkey, account: &'a AccountInfo) -> Result<Self, ProgramError> {
+        let data = load_account(
+            program_id,
+            account,
+            &T::discriminator(),
+            CAPNP_ACCOUNT_MIN_LEN,
+        )?;
+        let data = Ref::map(data, |data| &**data);
+        let version = capnp_schema_version(&data);
+        if version > T::LATEST_SCHEMA_VERSION {
+            msg!("Unknown {} schema version {}", T::TYPE_NAME, version);
+            return Err(DemoError::SchemaVersion.into());
+        }
+        let encoding = capnp_message_encoding(&data)?;
+
+        Ok(CapnpAccountData {
+            data,
+            version,
+            encoding,
+            account_type: PhantomData,
+        })
+    }
+
+    /// Schema version the message was written with.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn encoding(&self) -> MessageEncoding {
+        self.encoding
+    }
+
+    /// The account's message. Unpacked accounts are read in place, without copying or
+    /// allocating; packed ones are unpacked onto the heap on every call.
+    pub fn message(&self) -> Result<CapnpMessage<'_>, ProgramError> {
+        read_capnp_message(&self.data)
+    }
+
+    /// A builder holding a copy of the message, in the layout of the account's schema version.
+    pub fn builder(&self) -> Result<CapnpBuilder<T>, ProgramError> {
+        let message = self.message()?;
+        let mut builder = Builder::new_default();
+        builder
+            .set_root(message.root::<T::Owned>()?)
+            .map_err(|_| DemoError::CapnpDecode)?;
+        Ok(CapnpBuilder::from_message(self.version, builder))
+    }
+}
+
+/// A `T` message being written, together with the schema version its layout follows.
+pub struct CapnpBuilder<T> {
+    message: Builder<HeapAllocator>,
+    version: u8,
+    account_type: PhantomData<T>,
+}
+
+impl<T: CapnpAccount> CapnpBuilder<T> {
+    /// An empty message at `T::SCHEMA_VERSION`.
+    pub fn new() -> Self {
+        let mut message = Builder::new_default();
+        message.init_root::<<T::Owned as Owned>::Builder<'_>>();
+        CapnpBuilder::from_message(T::SCHEMA_VERSION, message)
+    }
+
+    /// Wraps a message whose root is a `T` written with schema `version`.
+    pub fn from_message(version: u8, message: Builder<HeapAllocator>) -> Self {
+        CapnpBuilder {
+            message,
+            version,
+            account_type: PhantomData,
+        }
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn root(&mut self) -> Result<<T::Owned as Owned>::Builder<'_>, ProgramError> {
+        self.root_as::<T::Owned>()
+    }
+
+    /// The root struct through the generated type `O`, typically another schema version of `T`.
+    pub fn root_as<O: Owned>(&mut self) -> Result<O::Builder<'_>, ProgramError> {
+        self.message
+            .get_root()
+            .map_err(|_| DemoError::CapnpDecode.into())
+    }
+
+    /// Writes `value` over the root struct. Fields of the root `R` doesn't declare keep their
+    /// values.
+    pub fn write<R: CapnpSchema>(&mut self, value: &R) -> Result<(), ProgramError> {
+        value
+            .write_root(&mut self.message)
+            .map_err(|_| DemoError::CapnpDecode.into())
+    }
+
+    /// Serializes the message in `encoding`. Only what the root still reaches is written, so
+    /// text replaced through the builder takes no space.
+    pub fn encode(&self, encoding: MessageEncoding) -> Result<Vec<u8>, ProgramError> {
+        let root = self
+            .message
+            .get_root_as_reader::<<T::Owned as Owned>::Reader<'_>>()
+            .map_err(|_| DemoError::CapnpDecode)?;
+        let mut compact = Builder::new_default();
+        compact.set_root(root).map_err(|_| DemoError::CapnpDecode)?;
+        encode_capnp_message(&compact, encoding)
+    }
+
+    /// Writes the message into the existing `T` account `account` in the account's encoding,
+    /// growing or shrinking the account to fit it, and records the builder's schema version.
+    /// `funding_account` pays for growth and gets the rent back when the account shrinks.
+    pub fn store<'info>(
+        &self,
+        program_id: &Pubkey,
+        account: &AccountInfo<'info>,
+        funding_account: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> ProgramResult {
+        let encoding = CapnpAccountData::<T>::load(program_id, account)?.encoding();
+        let bytes = self.encode(encoding)?;
+        resize_account(
+            account,
+            funding_account,
+            system_program,
+            T::SIZE_POLICY.account_size_for_len(bytes.len()),
+        )?;
+
+        let mut data = account.data.borrow_mut();
+        data[SCHEMA_VERSION_OFFSET] = self.version;
+        data[CAPNP_HEADER_LEN..CAPNP_HEADER_LEN + bytes.len()].copy_from_slice(&bytes);
+        // Clear whatever is left of the previous, possibly longer, message.
+        data[CAPNP_HEADER_LEN + bytes.len()..].fill(0);
+
+        Ok(())
+    }
+}
+
+impl<T: CapnpAccount> Default for CapnpBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates the `T` account `funding_account` derives with `bump`, writes `builder` into it with
+/// `encoding` and makes sure `T`'s descriptor exists.
+///
+/// Accounts: the funding account (signer), the new account, `T`'s descriptor and the system
+/// program.
+pub fn create<T: CapnpAccount>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+    builder: &CapnpBuilder<T>,
+    encoding: MessageEncoding,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let funding_account = next_account_info(accounts_iter)?;
+    let pda_account = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    // Checking if passed PDA and expected PDA are equal
+    let bump = [bump];
+    let mut signers_seeds = T::seeds(funding_account.key);
+    signers_seeds.push(&bump);
+    let pda = Pubkey::create_program_address(&signers_seeds, program_id)?;
+    msg!("pda {:?}", pda);
+
+    if pda.ne(pda_account.key) {
+        return Err(DemoError::InvalidPda.into());
+    }
+
+    let bytes = builder.encode(encoding)?;
+    let account_size = T::SIZE_POLICY.account_size_for_len(bytes.len());
+
+    // Assessing required lamports and creating transaction instruction
+    let lamports_required = Rent::get()?.minimum_balance(account_size);
+    let create_pda_account_ix = system_instruction::create_account(
+        funding_account.key,
+        pda_account.key,
+        lamports_required,
+        account_size as u64,
+        program_id,
+    );
+    // Invoking the instruction but with PDAs as additional signer
+    invoke_signed(
+        &create_pda_account_ix,
+        &[
+            funding_account.clone(),
+            pda_account.clone(),
+            system_program.clone(),
+        ],
+        &[signers_seeds.as_slice()],
+    )?;
+
+    write_account::<T>(
+        &mut pda_account.data.borrow_mut(),
+        builder.version(),
+        encoding,
+        &bytes,
+    );
+
+    ensure_type_descriptor::<T>(
+        program_id,
+        funding_account,
+        pda_account_descriptor,
+        system_program,
+    )
+}
+
+/// Rewrites `account`, an account of the program holding some other type, as a `T` account
+/// holding `builder` in `encoding`, and makes sure `T`'s descriptor exists. The account keeps
+/// its address and is resized to fit; `funding_account` pays for growth and gets the rent back
+/// when it shrinks. Checking that the account may be rewritten is up to the caller.
+pub fn replace<'info, T: CapnpAccount>(
+    program_id: &Pubkey,
+    account: &AccountInfo<'info>,
+    funding_account: &AccountInfo<'info>,
+    descriptor: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    builder: &CapnpBuilder<T>,
+    encoding: MessageEncoding,
+) -> ProgramResult {
+    if account.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let bytes = builder.encode(encoding)?;
+    resize_account(
+        account,
+        funding_account,
+        system_program,
+        T::SIZE_POLICY.account_size_for_len(bytes.len()),
+    )?;
+    {
+        let mut data = account.data.borrow_mut();
+        // Nothing of the previous type may be left behind the new message.
+        data.fill(0);
+        write_account::<T>(&mut data, builder.version(), encoding, &bytes);
+    }
+
+    ensure_type_descriptor::<T>(program_id, funding_account, descriptor, system_program)
+}
+
+/// Writes the header of a `T` account followed by `bytes`, a message in `encoding`.
+fn write_account<T: CapnpAccount>(
+    data: &mut [u8],
+    version: u8,
+    encoding: MessageEncoding,
+    bytes: &[u8],
+) {
+    data[0..8].copy_from_slice(&T::discriminator());
+    data[INITIALIZED_OFFSET] = 1;
+    data[SCHEMA_VERSION_OFFSET] = version;
+    data[MESSAGE_ENCODING_OFFSET] = encoding as u8;
+    data[CAPNP_HEADER_LEN..CAPNP_HEADER_LEN + bytes.len()].copy_from_slice(bytes);
+}
+
+/// Checks that `descriptor` is `T`'s descriptor and creates it if it doesn't exist yet.
+fn ensure_type_descriptor<'info, T: CapnpAccount>(
+    program_id: &Pubkey,
+    funding_account: &AccountInfo<'info>,
+    descriptor: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    let (pda_descriptor, pda_descriptor_bump) = T::descriptor_address(program_id);
+    if pda_descriptor.ne(descriptor.key) {
+        return Err(DemoError::InvalidPda.into());
+    }
+
+    // Descriptors published before the type's current schemas still describe its accounts, and so
+    // do compiled schemas uploaded since: FinalizeSchema checked them against what they replaced.
+    if descriptor.owner.eq(program_id) {
+        let data = descriptor.data.borrow();
+        let (header, stored) = read_descriptor(&data)?;
+        let is_legacy = T::LEGACY_SCHEMAS
+            .iter()
+            .any(|&(encoding, schema)| encoding == header.encoding && schema == stored);
+        if is_legacy || header.encoding == SchemaEncoding::CapnpBinary {
+            return Ok(());
+        }
+    }
+
+    // Store the schema on-chain, unless an earlier account of the type already did.
+    ensure_descriptor(
+        program_id,
+        funding_account,
+        descriptor,
+        system_program,
+        &[
+            DESCRIPTOR_SEED,
+            DataFormat::Capnp.seed(),
+            T::TYPE_NAME.as_bytes(),
+            &[pda_descriptor_bump],
+        ],
+        T::SCHEMA_ENCODING,
+        T::SCHEMAS,
+    )
+}
+
+/// Closes the `T` account `account`, moving its lamports to `destination`. Checking who may close
+/// it is up to the caller.
+pub fn close<T: CapnpAccount>(
+    program_id: &Pubkey,
+    account: &AccountInfo,
+    destination: &AccountInfo,
+) -> ProgramResult {
+    CapnpAccountData::<T>::load(program_id, account)?;
+    close_account(account, destination)
+}
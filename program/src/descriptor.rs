@@ -0,0 +1,248 @@
+//! Layout of descriptor accounts.
+//!
+//! A descriptor account starts with a fixed size [`DescriptorHeader`] followed by the schema
+//! bytes, so off-chain readers can tell how the schema is encoded and check it arrived intact
+//! before using it.
+//!
+//! ```text
+//! [magic: 4][layout version: 1][encoding: 1][reserved: 6][payload len: u32 LE][sha256: 32][payload]
+//! ```
+//!
+//! The header is a whole number of words, so a compiled capnp schema can be read in place.
+//!
+//! Descriptor addresses are derived from `["descriptor", format, type name]`, so the capnp and
+//! Borsh schemas of one type live side by side without sharing an address.
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, hash::hash, msg, program::invoke_signed,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::error::DemoError;
+
+/// First bytes of every descriptor account.
+pub const DESCRIPTOR_MAGIC: [u8; 4] = *b"DSCR";
+
+/// Version of the header layout written by this program.
+pub const DESCRIPTOR_LAYOUT_VERSION: u8 = 1;
+
+/// Seed prefix of every descriptor address.
+pub const DESCRIPTOR_SEED: &[u8] = b"descriptor";
+
+/// Serialization format of the accounts a descriptor describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataFormat {
+    Capnp,
+    Borsh,
+}
+
+impl DataFormat {
+    /// Seed identifying the format in descriptor addresses.
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            DataFormat::Capnp => b"capnp",
+            DataFormat::Borsh => b"borsh",
+        }
+    }
+}
+
+/// Derives the address and bump of the descriptor for `type_name` accounts in `format`.
+pub fn find_descriptor_address(
+    program_id: &Pubkey,
+    format: DataFormat,
+    type_name: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DESCRIPTOR_SEED, format.seed(), type_name.as_bytes()],
+        program_id,
+    )
+}
+
+/// How the schema stored in a descriptor is encoded.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaEncoding {
+    /// `.capnp` schema source text.
+    CapnpText = 0,
+    /// Binary capnp `CodeGeneratorRequest`.
+    CapnpBinary = 1,
+    /// Borsh schema, as generated by `#[state_descriptor]`.
+    Borsh = 2,
+}
+
+impl TryFrom<u8> for SchemaEncoding {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SchemaEncoding::CapnpText),
+            1 => Ok(SchemaEncoding::CapnpBinary),
+            2 => Ok(SchemaEncoding::Borsh),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DescriptorHeader {
+    pub layout_version: u8,
+    pub encoding: SchemaEncoding,
+    pub payload_len: u32,
+    /// sha256 of the payload.
+    pub payload_hash: [u8; 32],
+}
+
+impl DescriptorHeader {
+    pub const LEN: usize = 4 + 1 + 1 + 6 + 4 + 32;
+
+    pub fn new(encoding: SchemaEncoding, payload: &[u8]) -> Self {
+        DescriptorHeader {
+            layout_version: DESCRIPTOR_LAYOUT_VERSION,
+            encoding,
+            payload_len: payload.len() as u32,
+            payload_hash: hash(payload).to_bytes(),
+        }
+    }
+
+    /// Size of a descriptor account holding this header and its payload.
+    pub fn account_size(&self) -> usize {
+        Self::LEN + self.payload_len as usize
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) -> Result<(), ProgramError> {
+        let dst = dst.get_mut(..Self::LEN).ok_or(DemoError::SizeOverflow)?;
+        dst[0..4].copy_from_slice(&DESCRIPTOR_MAGIC);
+        dst[4] = self.layout_version;
+        dst[5] = self.encoding as u8;
+        dst[6..12].fill(0);
+        dst[12..16].copy_from_slice(&self.payload_len.to_le_bytes());
+        dst[16..48].copy_from_slice(&self.payload_hash);
+        Ok(())
+    }
+
+    pub fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        if src.len() < Self::LEN || src[0..4] != DESCRIPTOR_MAGIC {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if src[4] != DESCRIPTOR_LAYOUT_VERSION {
+            msg!("Unsupported descriptor layout version {}", src[4]);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(DescriptorHeader {
+            layout_version: src[4],
+            encoding: SchemaEncoding::try_from(src[5])?,
+            payload_len: u32::from_le_bytes(src[12..16].try_into().unwrap()),
+            payload_hash: src[16..48].try_into().unwrap(),
+        })
+    }
+}
+
+/// Writes the header for `payload` followed by the payload itself into `data`.
+pub fn write_descriptor(
+    data: &mut [u8],
+    encoding: SchemaEncoding,
+    payload: &[u8],
+) -> Result<DescriptorHeader, ProgramError> {
+    let header = DescriptorHeader::new(encoding, payload);
+    if data.len() < header.account_size() {
+        return Err(DemoError::SizeOverflow.into());
+    }
+
+    header.pack(data)?;
+    data[DescriptorHeader::LEN..header.account_size()].copy_from_slice(payload);
+    Ok(header)
+}
+
+/// Reads a descriptor account, returning its header and the schema bytes after checking them
+/// against the stored length and hash.
+pub fn read_descriptor(data: &[u8]) -> Result<(DescriptorHeader, &[u8]), ProgramError> {
+    let header = DescriptorHeader::unpack(data)?;
+    let payload = data
+        .get(DescriptorHeader::LEN..header.account_size())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    if hash(payload).to_bytes() != header.payload_hash {
+        msg!("Descriptor payload does not match its hash");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok((header, payload))
+}
+
+/// Makes sure `descriptor` holds one of `payloads`, creating the account with the first of them on
+/// first use.
+///
+/// Descriptors are shared by every account of a type, so only the first initialize pays for one.
+/// Later calls find it owned by the program and only check that it still holds a schema this
+/// program was compiled with. Passing several payloads lets a descriptor keep any schema version
+/// that can read the accounts being written. `seeds` are the descriptor PDA's seeds, bump included.
+pub fn ensure_descriptor<'a>(
+    program_id: &Pubkey,
+    funding_account: &AccountInfo<'a>,
+    descriptor: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    seeds: &[&[u8]],
+    encoding: SchemaEncoding,
+    payloads: &[&[u8]],
+) -> ProgramResult {
+    if descriptor.owner.eq(program_id) {
+        let data = descriptor.data.borrow();
+        let (header, stored) = read_descriptor(&data)?;
+        if header.encoding != encoding || !payloads.contains(&stored) {
+            msg!("Descriptor {} holds a different schema", descriptor.key);
+            return Err(DemoError::DescriptorMismatch.into());
+        }
+        return Ok(());
+    }
+    if descriptor.owner.ne(&system_program::id()) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let payload = *payloads.first().ok_or(ProgramError::InvalidArgument)?;
+    let header = DescriptorHeader::new(encoding, payload);
+    let lamports_required = Rent::get()?.minimum_balance(header.account_size());
+    let accounts = [
+        funding_account.clone(),
+        descriptor.clone(),
+        system_program.clone(),
+    ];
+
+    if descriptor.lamports() == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                funding_account.key,
+                descriptor.key,
+                lamports_required,
+                header.account_size() as u64,
+                program_id,
+            ),
+            &accounts,
+            &[seeds],
+        )?;
+    } else {
+        // Someone already sent lamports to the address, which makes create_account fail. Top it
+        // up and allocate it step by step instead.
+        let missing = lamports_required.saturating_sub(descriptor.lamports());
+        if missing > 0 {
+            invoke_signed(
+                &system_instruction::transfer(funding_account.key, descriptor.key, missing),
+                &accounts,
+                &[],
+            )?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(descriptor.key, header.account_size() as u64),
+            &accounts,
+            &[seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(descriptor.key, program_id),
+            &accounts,
+            &[seeds],
+        )?;
+    }
+
+    write_descriptor(&mut descriptor.data.borrow_mut(), encoding, payload)?;
+    Ok(())
+}
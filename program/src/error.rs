@@ -0,0 +1,37 @@
+use solana_program::program_error::ProgramError;
+
+/// Errors specific to the demo program. They reach clients as `ProgramError::Custom`, with the
+/// discriminant as the error code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DemoError {
+    /// An account is not the PDA derived from the expected seeds.
+    InvalidPda = 0,
+    /// An account does not start with the discriminator of the expected type.
+    WrongDiscriminator = 1,
+    /// Cap'n Proto instruction or account data could not be decoded.
+    CapnpDecode = 2,
+    /// Borsh instruction or account data could not be (de)serialized.
+    Borsh = 3,
+    /// Data does not fit in the space available for it.
+    SizeOverflow = 4,
+    /// An account is shorter than the smallest valid account of its type.
+    AccountTooShort = 5,
+    /// An account has not been initialized.
+    Uninitialized = 6,
+    /// A descriptor account holds a different schema than the one the program was built with.
+    DescriptorMismatch = 7,
+    /// A Person account is not at the schema version the instruction expects.
+    SchemaVersion = 8,
+    /// The signer is not the authority of a Person account.
+    InvalidAuthority = 9,
+    /// An uploaded schema does not match the hash it was finalized with.
+    SchemaHash = 10,
+    /// An uploaded schema no longer describes the accounts of its descriptor.
+    IncompatibleSchema = 11,
+}
+
+impl From<DemoError> for ProgramError {
+    fn from(e: DemoError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
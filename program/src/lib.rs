@@ -1,5 +1,9 @@
+// Nothing here names these: `#[state_descriptor]` derives the Borsh traits, BorshSchema among
+// them, for PersonState and implements `DescriptorSerialize` over `Write`, with the names in scope.
+#[allow(unused_imports)]
 use std::io::Write;
 
+#[allow(unused_imports)]
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use bryte_descriptor_attribute::state_descriptor;
 use bryte_descriptor_state::states::Descriptor;
@@ -9,27 +13,72 @@ use bryte_descriptor_state::states::{
 };
 use capnp::message::ReaderOptions;
 use capnp::serialize;
+use capnp_models::compatibility::{check_compatibility, find_struct};
+use capnp_models::schema_capnp::code_generator_request;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
-    entrypoint,
-    entrypoint::ProgramResult,
+    bpf_loader_upgradeable,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    hash::hash,
     msg,
-    program::invoke_signed,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
     system_instruction,
     sysvar::Sysvar,
 };
-use bryte_descriptor_state::discriminators::custom_discriminator;
 
-entrypoint!(instruction);
+use crate::account::{
+    account_type, load_borsh_person, MessageEncoding, BORSH_PERSON_ACCOUNT_SIZE, CAPNP_HEADER_LEN,
+    PERSON_TYPE_NAME, PERSON_V1, PERSON_V2,
+};
+use crate::capnp_account::CapnpAccount;
+use crate::descriptor::{
+    ensure_descriptor, find_descriptor_address, read_descriptor, write_descriptor, DataFormat,
+    DescriptorHeader, SchemaEncoding, DESCRIPTOR_SEED,
+};
+use crate::error::DemoError;
+use crate::instruction_capnp::{demo_instruction, migrate_person};
+use crate::person::{Person, PersonAccount, PersonBuilder};
+use crate::schema_buffer::{read_schema_buffer, SchemaBufferHeader};
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(instruction);
+
+pub mod account;
+pub mod capnp_account;
+pub mod descriptor;
+pub mod error;
+pub mod person;
+pub mod schema_buffer;
 
 pub mod person_capnp {
     include!("../../capnp-models/src/person_capnp.rs");
 }
 
-const PERSON_SCHEMA: &[u8] = include_bytes!("../../capnp-models/src/person.capnp");
+pub mod person_v2_capnp {
+    include!("../../capnp-models/src/person_v2_capnp.rs");
+}
+
+pub mod instruction_capnp {
+    include!("../../capnp-models/src/instruction_capnp.rs");
+}
+
+/// person.capnp, the schema capnp Person accounts are initialized with, as the binary
+/// `CodeGeneratorRequest` descriptors publish.
+const PERSON_SCHEMA: &[u8] = capnp_models::PERSON_SCHEMA_REQUESTS[0];
+
+/// person_v2.capnp, the schema [`migrate_person`] moves Person accounts to. It reads v1 data as
+/// well, so it is what new descriptors hold.
+const PERSON_SCHEMA_V2: &[u8] = capnp_models::PERSON_SCHEMA_REQUESTS[1];
+
+/// The `.capnp` text of both Person schemas, which descriptors held before they published
+/// compiled schemas.
+const PERSON_SCHEMA_TEXTS: [&[u8]; 2] = [
+    capnp_models::PERSON_SCHEMAS[0].as_bytes(),
+    capnp_models::PERSON_SCHEMAS[1].as_bytes(),
+];
 
 #[state_descriptor]
 #[derive(Default, Debug)]
@@ -37,50 +86,130 @@ pub struct PersonState {
     is_initialized: bool,
     first_name: String,
     last_name: String,
+    authority: Pubkey,
 }
 
 impl PersonState {
-    const SIZE: usize = 8 + 1 + 24 + 24;
-}
-
-#[derive(Debug)]
-pub enum DemoInstruction {
-    InitializeCapnp { bump: Vec<u8> },
-    DeserializeCapnpDemo,
-    InitializeBorsh { bump: Vec<u8> },
-    DeserializeBorshDemo,
-}
-
-#[derive(BorshDeserialize)]
-pub struct DemoPayload {
-    bump: Vec<u8>,
-}
-
-impl DemoInstruction {
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        let (&variant, rest) = input
-            .split_first()
-            .ok_or(ProgramError::InvalidInstructionData)?;
-
-        Ok(match variant {
-            0 => DemoInstruction::InitializeCapnp {
-                bump: rest.to_vec(),
-            },
-            1 => DemoInstruction::DeserializeCapnpDemo,
-            2 => DemoInstruction::InitializeBorsh {
-                bump: rest.to_vec(),
-            },
-            3 => DemoInstruction::DeserializeBorshDemo,
-            _ => return Err(ProgramError::InvalidInstructionData),
-        })
+    const SIZE: usize = 8 + 1 + 24 + 24 + 32;
+
+    /// The account's authority. Accounts written before authorities existed hold the default
+    /// pubkey and have none.
+    pub fn authority(&self) -> Option<Pubkey> {
+        if self.authority == Pubkey::default() {
+            None
+        } else {
+            Some(self.authority)
+        }
+    }
+
+    pub fn first_name(&self) -> &str {
+        &self.first_name
     }
+
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+}
+
+/// Upper bound, in bytes, for the first and last name of a Person.
+pub const MAX_NAME_LEN: usize = 64;
+
+/// Upper bound, in bytes, for the email and each address line of a v2 Person.
+pub const MAX_FIELD_LEN: usize = 128;
+
+/// How a Person account is sized around the serialized message it holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizePolicy {
+    /// The account header plus the message, nothing more.
+    Exact,
+    /// Rounds the account size up to a multiple of the given number of bytes, so small edits to
+    /// a Person don't realloc (and re-rent) the account every time.
+    RoundUp(usize),
 }
 
+impl SizePolicy {
+    /// Account size for a message of `message_words` words, including the account header.
+    pub fn account_size(&self, message_words: usize) -> usize {
+        self.account_size_for_len(message_words * 8)
+    }
+
+    /// Account size for a message serialized into `message_len` bytes, including the account
+    /// header. Packed messages aren't a whole number of words.
+    pub fn account_size_for_len(&self, message_len: usize) -> usize {
+        let size = CAPNP_HEADER_LEN + message_len;
+        match *self {
+            SizePolicy::Exact => size,
+            SizePolicy::RoundUp(multiple) if multiple > 1 => {
+                (size + multiple - 1) / multiple * multiple
+            }
+            SizePolicy::RoundUp(_) => size,
+        }
+    }
+}
+
+/// Size policy used for capnp Person accounts.
+pub const PERSON_SIZE_POLICY: SizePolicy = SizePolicy::RoundUp(64);
+
+/// Checks the names a client wants to store in a Person account.
+fn validate_person_names(firstname: &str, lastname: &str) -> ProgramResult {
+    for name in [firstname, lastname] {
+        if name.is_empty() || name.len() > MAX_NAME_LEN {
+            msg!("Person names must be between 1 and {} bytes", MAX_NAME_LEN);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+    Ok(())
+}
+
+/// Checks the optional Text fields person_v2.capnp adds to a Person.
+fn validate_person_fields<'a>(fields: impl IntoIterator<Item = &'a str>) -> ProgramResult {
+    for field in fields {
+        if field.len() > MAX_FIELD_LEN {
+            msg!("Person fields can be at most {} bytes", MAX_FIELD_LEN);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+    Ok(())
+}
+
+/// Creates a capnp Person account holding `person`, with the funding account as its authority.
 pub fn initialize_capnp(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    pda_bump: u8,
+    person: person_capnp::person::Reader,
+    encoding: MessageEncoding,
+) -> ProgramResult {
+    let funding_account = next_account_info(&mut accounts.iter())?;
+
+    // Validate the client supplied Person and copy its names into a fresh message. The funding
+    // account becomes the Person's authority, whatever the client sent.
+    validate_person_names(
+        person_text(person.get_firstname())?,
+        person_text(person.get_lastname())?,
+    )?;
+
+    let builder = PersonBuilder::new_v1(
+        person.get_firstname().map_err(|_| DemoError::CapnpDecode)?,
+        person.get_lastname().map_err(|_| DemoError::CapnpDecode)?,
+        Some(funding_account.key),
+    );
+
+    capnp_account::create(program_id, accounts, pda_bump, &builder, encoding)
+}
+
+/// Reads a Text field of a client supplied Person.
+fn person_text(text: capnp::Result<capnp::text::Reader>) -> Result<&str, ProgramError> {
+    text.and_then(|text| Ok(text.to_str()?))
+        .map_err(|_| DemoError::CapnpDecode.into())
+}
+
+pub fn deserialize_capnpn_demo(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    // TODO clean up unnecessary accounts.
     let accounts_iter = &mut accounts.iter();
     // Getting required accounts
     let funding_account = next_account_info(accounts_iter)?;
@@ -88,120 +217,273 @@ pub fn initialize_capnp(
     let pda_account_descriptor = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
 
-    // Getting PDA Bump from instruction data
-    let (pda_bump, _) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
+    let person_account = PersonAccount::load(program_id, pda_account)?;
+    msg!("Encoding: {:?}", person_account.encoding());
+    // Use capnp reader to deserialize the data. Benchmark builds log the compute units around it,
+    // what decoding costs in the account's encoding.
+    #[cfg(all(target_os = "solana", feature = "benchmark"))]
+    solana_program::log::sol_log_compute_units();
+    let message = person_account.message()?;
 
-    // Checking if passed PDA and expected PDA are equal
-    let signers_seeds: &[&[u8]; 3] = &[
-        b"customaddress",
-        &funding_account.key.to_bytes(),
-        &[*pda_bump],
-    ];
-    let pda = Pubkey::create_program_address(signers_seeds, program_id)?;
-    msg!("pda {:?}", pda);
+    let person = message.root::<person_capnp::person::Owned>()?;
+    msg!("{:?} {:?}", person.get_firstname(), person.get_lastname());
+    #[cfg(all(target_os = "solana", feature = "benchmark"))]
+    solana_program::log::sol_log_compute_units();
 
-    if pda.ne(&pda_account.key) {
-        return Err(ProgramError::InvalidAccountData);
-    }
+    Ok(())
+}
 
-    let mut message = capnp::message::Builder::new_default();
-    let mut person_state_builder = message.init_root::<person_capnp::person::Builder>();
-    person_state_builder.set_firstname("Captain".into());
-    person_state_builder.set_lastname("Proto".into());
+/// Replaces the names of a capnp Person. The Person's authority has to sign and pays for the
+/// account to grow.
+pub fn update_capnp(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+    person: person_capnp::person::Reader,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let pda_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
 
+    validate_person_names(
+        person_text(person.get_firstname())?,
+        person_text(person.get_lastname())?,
+    )?;
 
-    // Potentially use this size to do space allocation
-    // let size = serialize::compute_serialized_size_in_words(&message) + 8;
+    let builder = {
+        let person_account = PersonAccount::load(program_id, pda_account)?;
+        let stored_authority = person_account.authority()?;
+        check_authority(program_id, pda_account, authority, stored_authority, bump)?;
 
-    // Assessing required lamports and creating transaction instruction
-    let lamports_required = Rent::get()?.minimum_balance(200);
-    let create_pda_account_ix = system_instruction::create_account(
-        &funding_account.key,
-        &pda_account.key,
-        lamports_required,
-        200,
-        &program_id,
-    );
-    // Invoking the instruction but with PDAs as additional signer
-    invoke_signed(
-        &create_pda_account_ix,
-        &[
-            funding_account.clone(),
-            pda_account.clone(),
-            system_program.clone(),
-        ],
-        &[signers_seeds],
-    )?;
+        let mut builder = person_account.builder()?;
+        let mut updated = builder.root()?;
+        updated.set_firstname(person.get_firstname().map_err(|_| DemoError::CapnpDecode)?);
+        updated.set_lastname(person.get_lastname().map_err(|_| DemoError::CapnpDecode)?);
+        builder.set_authority(&stored_authority.unwrap_or(*authority.key))?;
+        builder
+    };
 
-    let discriminator = custom_discriminator("account".to_string(), "person".to_string());
-    &pda_account.data.borrow_mut()[0..8].copy_from_slice(&discriminator);
-    serialize::write_message(&mut &mut pda_account.data.borrow_mut()[8..], &message)
-        .expect("Error serializing data using capnp");
+    builder.store(program_id, pda_account, authority, system_program)
+}
 
-    let (pda_descriptor, pda_descriptor_bump) =
-        Pubkey::find_program_address(&[&discriminator], &program_id);
+/// Reads a 32 byte Data field holding a pubkey, `None` if the field is not set.
+pub(crate) fn pubkey_field(
+    has_field: bool,
+    field: capnp::Result<capnp::data::Reader>,
+) -> Result<Option<Pubkey>, ProgramError> {
+    if !has_field {
+        return Ok(None);
+    }
+    let bytes = field.map_err(|_| DemoError::CapnpDecode)?;
+    Pubkey::try_from(bytes)
+        .map(Some)
+        .map_err(|_| DemoError::CapnpDecode.into())
+}
 
-    if pda_descriptor.ne(&pda_account_descriptor.key) {
-        return Err(ProgramError::InvalidAccountData);
+/// Checks that `authority` signed and may change `pda_account`, whose stored authority is
+/// `stored`. Persons written before authorities existed have none; for those the signer must be
+/// the funding account their PDA was derived from, with `bump`.
+fn check_authority(
+    program_id: &Pubkey,
+    pda_account: &AccountInfo,
+    authority: &AccountInfo,
+    stored: Option<Pubkey>,
+    bump: u8,
+) -> ProgramResult {
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // TODO get the required space dynamically...
-    let lamports_required = Rent::get()?.minimum_balance(193);
-    let create_pda_account_descriptor_ix = system_instruction::create_account(
-        &funding_account.key,
-        &pda_descriptor,
-        lamports_required,
-        193,
-        &program_id,
-    );
+    let allowed = match stored {
+        Some(stored) => stored.eq(authority.key),
+        None => {
+            let pda = Pubkey::create_program_address(
+                &[b"customaddress", &authority.key.to_bytes(), &[bump]],
+                program_id,
+            );
+            pda.ok() == Some(*pda_account.key)
+        }
+    };
+    if !allowed {
+        msg!(
+            "{} is not the authority of {}",
+            authority.key,
+            pda_account.key
+        );
+        return Err(DemoError::InvalidAuthority.into());
+    }
+    Ok(())
+}
 
-    // Invoking the instruction but with PDAs as additional signer
-    invoke_signed(
-        &create_pda_account_descriptor_ix,
-        &[
-            funding_account.clone(),
-            pda_account_descriptor.clone(),
-            system_program.clone(),
-        ],
-        &[&[&discriminator, &[pda_descriptor_bump]]],
-    )?;
+/// Returns the format and stored authority of a capnp or Borsh Person account.
+fn load_person_authority(
+    program_id: &Pubkey,
+    pda_account: &AccountInfo,
+) -> Result<(DataFormat, Option<Pubkey>), ProgramError> {
+    let format = {
+        let data = pda_account.data.borrow();
+        let discriminator = data.get(0..8).ok_or(DemoError::AccountTooShort)?;
+        account_type(discriminator)
+            .ok_or(DemoError::WrongDiscriminator)?
+            .0
+    };
 
-    // Store the person.capnp schema on-chain
-    &pda_account_descriptor.data.borrow_mut()[..].copy_from_slice(&PERSON_SCHEMA);
+    let authority = match format {
+        DataFormat::Capnp => PersonAccount::load(program_id, pda_account)?.authority()?,
+        DataFormat::Borsh => load_borsh_person(program_id, pda_account)?.authority(),
+    };
+    Ok((format, authority))
+}
 
-    Ok(())
+/// Builds the person_v2.capnp version of the v1 `person`, taking the fields v2 adds from `params`.
+/// The migrated Person gets `authority`.
+pub fn migrate_person_message(
+    person: person_capnp::person::Reader,
+    params: migrate_person::Reader,
+    authority: &Pubkey,
+) -> Result<capnp::message::Builder<capnp::message::HeapAllocator>, ProgramError> {
+    let address = if params.has_address() {
+        Some(params.get_address().map_err(|_| DemoError::CapnpDecode)?)
+    } else {
+        None
+    };
+    let mut fields = vec![person_text(params.get_email())?];
+    if let Some(address) = address {
+        fields.extend([
+            person_text(address.get_street())?,
+            person_text(address.get_city())?,
+            person_text(address.get_postal_code())?,
+            person_text(address.get_country())?,
+        ]);
+    }
+    validate_person_fields(fields)?;
+
+    let mut message = capnp::message::Builder::new_default();
+    let mut migrated = message.init_root::<person_v2_capnp::person::Builder>();
+    migrated.set_firstname(person.get_firstname().map_err(|_| DemoError::CapnpDecode)?);
+    migrated.set_lastname(person.get_lastname().map_err(|_| DemoError::CapnpDecode)?);
+    migrated.set_authority(&authority.to_bytes());
+    migrated.set_email(params.get_email().map_err(|_| DemoError::CapnpDecode)?);
+    migrated.set_birth_year(params.get_birth_year());
+    if let Some(address) = address {
+        migrated
+            .set_address(address)
+            .map_err(|_| DemoError::CapnpDecode)?;
+    }
+
+    Ok(message)
 }
 
-pub fn deserialize_capnpn_demo(
+/// Rewrites a capnp Person account written with person.capnp under person_v2.capnp, and moves the
+/// shared descriptor to person_v2.capnp if it still holds v1. The Person's authority has to sign.
+pub fn migrate_person(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    params: migrate_person::Reader,
 ) -> ProgramResult {
-    // TODO clean up unnecessary accounts.
     let accounts_iter = &mut accounts.iter();
     // Getting required accounts
-    let funding_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
     let pda_account = next_account_info(accounts_iter)?;
     let pda_account_descriptor = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
 
-    // Get the bytes after the discriminator.
-    let mut data = &pda_account.data.borrow_mut()[8..];
-    // Use capnp reader to deserialize the data
-    let reader = serialize::read_message_from_flat_slice_no_alloc(&mut data, ReaderOptions::new()).unwrap();
+    let (pda_descriptor, _) =
+        find_descriptor_address(program_id, DataFormat::Capnp, PERSON_TYPE_NAME);
+    if pda_descriptor.ne(&pda_account_descriptor.key) {
+        return Err(DemoError::InvalidPda.into());
+    }
+
+    let builder = {
+        let person_account = PersonAccount::load(program_id, pda_account)?;
+        let version = person_account.version();
+        if version != PERSON_V1 {
+            msg!(
+                "Person is at schema version {}, expected {}",
+                version,
+                PERSON_V1
+            );
+            return Err(DemoError::SchemaVersion.into());
+        }
+
+        let stored_authority = person_account.authority()?;
+        let message = person_account.message()?;
+        let person = message.root::<person_capnp::person::Owned>()?;
+        check_authority(
+            program_id,
+            pda_account,
+            authority,
+            stored_authority,
+            params.get_bump(),
+        )?;
+        let migrated =
+            migrate_person_message(person, params, &stored_authority.unwrap_or(*authority.key))?;
+        PersonBuilder::from_message(PERSON_V2, migrated)
+    };
 
-    let person = reader.get_root::<person_capnp::person::Reader>().unwrap();
-    msg!("{:?} {:?}",  person.get_firstname(),  person.get_lastname());
+    builder.store(program_id, pda_account, authority, system_program)?;
 
+    upgrade_person_descriptor(
+        program_id,
+        pda_account_descriptor,
+        authority,
+        system_program,
+    )
+}
+
+/// Replaces person.capnp with person_v2.capnp in the capnp Person descriptor, publishing it
+/// compiled. Descriptors that already hold compiled v2, or a compiled schema uploaded since, are
+/// left as they are, those holding either schema as text are moved to compiled v2.
+fn upgrade_person_descriptor<'a>(
+    program_id: &Pubkey,
+    descriptor: &AccountInfo<'a>,
+    funding_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if descriptor.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    {
+        let data = descriptor.data.borrow();
+        let (header, stored) = read_descriptor(&data)?;
+        let known = match header.encoding {
+            SchemaEncoding::CapnpBinary if stored == PERSON_SCHEMA => true,
+            // person_v2.capnp, or an upload FinalizeSchema checked against it.
+            SchemaEncoding::CapnpBinary => return Ok(()),
+            SchemaEncoding::CapnpText => PERSON_SCHEMA_TEXTS.iter().any(|text| *text == stored),
+            SchemaEncoding::Borsh => false,
+        };
+        if !known {
+            msg!(
+                "Descriptor {} holds an unknown Person schema",
+                descriptor.key
+            );
+            return Err(DemoError::DescriptorMismatch.into());
+        }
+    }
+
+    let header = DescriptorHeader::new(SchemaEncoding::CapnpBinary, PERSON_SCHEMA_V2);
+    resize_account(
+        descriptor,
+        funding_account,
+        system_program,
+        header.account_size(),
+    )?;
+    write_descriptor(
+        &mut descriptor.data.borrow_mut(),
+        SchemaEncoding::CapnpBinary,
+        PERSON_SCHEMA_V2,
+    )?;
     Ok(())
 }
 
 pub fn initialize_borsh(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    pda_bump: u8,
+    person_state_data: &[u8],
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
     // Getting required accounts
@@ -210,31 +492,33 @@ pub fn initialize_borsh(
     let pda_account_descriptor = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
 
-    // Getting PDA Bump from instruction data
-    let (pda_bump, _) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-
     // Checking if passed PDA and expected PDA are equal
     let signers_seeds: &[&[u8]; 3] = &[
         b"customaddress",
         &funding_account.key.to_bytes(),
-        &[*pda_bump],
+        &[pda_bump],
     ];
     let pda = Pubkey::create_program_address(signers_seeds, program_id)?;
     msg!("pda {:?}", pda);
 
     if pda.ne(&pda_account.key) {
-        return Err(ProgramError::InvalidAccountData);
+        return Err(DemoError::InvalidPda.into());
     }
 
+    // Validate the client supplied PersonState
+    let mut pda_account_state =
+        PersonState::try_from_slice(person_state_data).map_err(|_| DemoError::Borsh)?;
+    validate_person_names(&pda_account_state.first_name, &pda_account_state.last_name)?;
+    pda_account_state.is_initialized = true;
+    pda_account_state.authority = *funding_account.key;
+
     // Assessing required lamports and creating transaction instruction
-    let lamports_required = Rent::get()?.minimum_balance(500);
+    let lamports_required = Rent::get()?.minimum_balance(BORSH_PERSON_ACCOUNT_SIZE);
     let create_pda_account_ix = system_instruction::create_account(
         &funding_account.key,
         &pda_account.key,
         lamports_required,
-        500,
+        BORSH_PERSON_ACCOUNT_SIZE as u64,
         &program_id,
     );
     // Invoking the instruction but with PDAs as additional signer
@@ -249,47 +533,56 @@ pub fn initialize_borsh(
     )?;
 
     // Setting state for PDA
-    let pda_account_state = PersonState {
-        is_initialized: true,
-        first_name: "John".to_string(),
-        last_name: "Borsh".to_string(),
-    };
-    pda_account_state.try_serialize(&mut &mut pda_account.data.borrow_mut()[..]);
+    pda_account_state
+        .try_serialize(&mut &mut pda_account.data.borrow_mut()[..])
+        .map_err(|_| DemoError::SizeOverflow)?;
 
+    ensure_borsh_person_descriptor(
+        program_id,
+        funding_account,
+        pda_account_descriptor,
+        system_program,
+    )
+}
+
+/// Checks that `descriptor` is the Borsh Person descriptor and creates it if it doesn't exist yet.
+fn ensure_borsh_person_descriptor<'a>(
+    program_id: &Pubkey,
+    funding_account: &AccountInfo<'a>,
+    descriptor: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
     let (pda_descriptor, pda_descriptor_bump) =
-        Pubkey::find_program_address(&[&PersonState::DISCRIMINATOR], &program_id);
+        find_descriptor_address(program_id, DataFormat::Borsh, PERSON_TYPE_NAME);
 
-    if pda_descriptor.ne(&pda_account_descriptor.key) {
-        return Err(ProgramError::InvalidAccountData);
+    if pda_descriptor.ne(&descriptor.key) {
+        return Err(DemoError::InvalidPda.into());
     }
 
-    // Assessing required lamports and creating transaction instruction
-    let lamports_required = Rent::get()?.minimum_balance(PersonStateDescriptor::size());
-    let create_pda_account_descriptor_ix = system_instruction::create_account(
-        &funding_account.key,
-        &pda_descriptor,
-        lamports_required,
-        PersonStateDescriptor::size().try_into().unwrap(),
-        &program_id,
-    );
-
-    // Invoking the instruction but with PDAs as additional signer
-    invoke_signed(
-        &create_pda_account_descriptor_ix,
+    let descriptor_payload = borsh_person_schema()?;
+    ensure_descriptor(
+        program_id,
+        funding_account,
+        descriptor,
+        system_program,
         &[
-            funding_account.clone(),
-            pda_account_descriptor.clone(),
-            system_program.clone(),
+            DESCRIPTOR_SEED,
+            DataFormat::Borsh.seed(),
+            PERSON_TYPE_NAME.as_bytes(),
+            &[pda_descriptor_bump],
         ],
-        &[&[&PersonState::DISCRIMINATOR, &[pda_descriptor_bump]]],
-    )?;
-
-    // Setting state for PDA
-    let pda_account_state_descriptor = PersonStateDescriptor::default();
-    pda_account_state_descriptor
-        .try_serialize(&mut &mut pda_account_descriptor.data.borrow_mut()[..]);
+        SchemaEncoding::Borsh,
+        &[&descriptor_payload],
+    )
+}
 
-    Ok(())
+/// The serialized PersonStateDescriptor, the schema the Borsh Person descriptor holds.
+fn borsh_person_schema() -> Result<Vec<u8>, ProgramError> {
+    let mut descriptor_payload = Vec::with_capacity(PersonStateDescriptor::size());
+    PersonStateDescriptor::default()
+        .try_serialize(&mut descriptor_payload)
+        .map_err(|_| DemoError::Borsh)?;
+    Ok(descriptor_payload)
 }
 
 pub fn deserialize_borsh_demo(
@@ -304,37 +597,736 @@ pub fn deserialize_borsh_demo(
     let pda_account_descriptor = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
 
-    let mut data = &pda_account.data.borrow_mut()[..];
-    let person_state = PersonState::try_deserialize_unchecked(&mut data).unwrap();
+    let person_state = load_borsh_person(program_id, pda_account)?;
 
     msg!("{:?} {:?}", person_state.first_name, person_state.last_name);
     Ok(())
 }
 
+/// Closes a capnp or Borsh Person account. Only the Person's authority may close it, and it gets
+/// the rent back.
+pub fn close(program_id: &Pubkey, accounts: &[AccountInfo], bump: u8) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let pda_account = next_account_info(accounts_iter)?;
+
+    let (format, stored_authority) = load_person_authority(program_id, pda_account)?;
+    check_authority(program_id, pda_account, authority, stored_authority, bump)?;
+
+    match format {
+        DataFormat::Capnp => capnp_account::close::<Person>(program_id, pda_account, authority),
+        DataFormat::Borsh => close_account(pda_account, authority),
+    }
+}
+
+/// Hands a capnp or Borsh Person account off to `new_authority`. The current authority has to
+/// sign and pays if the account grows.
+pub fn set_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+    new_authority: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let pda_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    let new_authority =
+        Pubkey::try_from(new_authority).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let (format, stored_authority) = load_person_authority(program_id, pda_account)?;
+    check_authority(program_id, pda_account, authority, stored_authority, bump)?;
+
+    match format {
+        DataFormat::Capnp => {
+            let mut builder = PersonAccount::load(program_id, pda_account)?.builder()?;
+            builder.set_authority(&new_authority)?;
+            builder.store(program_id, pda_account, authority, system_program)?;
+        }
+        DataFormat::Borsh => {
+            let mut person_state = load_borsh_person(program_id, pda_account)?;
+            person_state.authority = new_authority;
+            person_state
+                .try_serialize(&mut &mut pda_account.data.borrow_mut()[..])
+                .map_err(|_| DemoError::SizeOverflow)?;
+        }
+    }
+
+    msg!(
+        "{} is now the authority of {}",
+        new_authority,
+        pda_account.key
+    );
+    Ok(())
+}
+
+/// Builds the capnp Person holding what `person_state` holds. A PersonState without an authority
+/// gives a Person without one.
+pub fn person_state_to_capnp(person_state: &PersonState) -> Result<PersonBuilder, ProgramError> {
+    Ok(PersonBuilder::new_v1(
+        person_state.first_name.as_str().into(),
+        person_state.last_name.as_str().into(),
+        person_state.authority().as_ref(),
+    ))
+}
+
+/// The PersonState holding what `person` holds, the inverse of [`person_state_to_capnp`]. The
+/// Person is read through person_v2.capnp, which declares its authority.
+pub fn capnp_to_person_state(
+    person: person_v2_capnp::person::Reader,
+) -> Result<PersonState, ProgramError> {
+    Ok(PersonState {
+        is_initialized: true,
+        first_name: person_text(person.get_firstname())?.to_string(),
+        last_name: person_text(person.get_lastname())?.to_string(),
+        authority: pubkey_field(person.has_authority(), person.get_authority())?
+            .unwrap_or_default(),
+    })
+}
+
+/// Rewrites a Borsh Person account as a capnp Person in `encoding`, at the same address and with
+/// the same names and authority, and creates the capnp Person descriptor if there is none yet.
+/// The Person's authority has to sign and pays if the account grows.
+pub fn convert_borsh_to_capnp(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+    encoding: MessageEncoding,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let pda_account = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    let person_state = load_borsh_person(program_id, pda_account)?;
+    check_authority(
+        program_id,
+        pda_account,
+        authority,
+        person_state.authority(),
+        bump,
+    )?;
+
+    let mut builder = person_state_to_capnp(&person_state)?;
+    if person_state.authority().is_none() {
+        builder.set_authority(authority.key)?;
+    }
+
+    capnp_account::replace(
+        program_id,
+        pda_account,
+        authority,
+        pda_account_descriptor,
+        system_program,
+        &builder,
+        encoding,
+    )
+}
+
+/// Rewrites a capnp Person account as a Borsh PersonState, at the same address and with the same
+/// names and authority, and creates the Borsh Person descriptor if there is none yet. Only
+/// version 1 Persons convert, PersonState has nowhere to keep what v2 adds. The Person's
+/// authority has to sign and pays if the account grows.
+pub fn convert_capnp_to_borsh(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let pda_account = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    let mut person_state = {
+        let person_account = PersonAccount::load(program_id, pda_account)?;
+        let version = person_account.version();
+        if version != PERSON_V1 {
+            msg!(
+                "Person is at schema version {}, only version {} converts to Borsh",
+                version,
+                PERSON_V1
+            );
+            return Err(DemoError::SchemaVersion.into());
+        }
+
+        let message = person_account.message()?;
+        capnp_to_person_state(message.root::<person_v2_capnp::person::Owned>()?)?
+    };
+    check_authority(
+        program_id,
+        pda_account,
+        authority,
+        person_state.authority(),
+        bump,
+    )?;
+    if person_state.authority().is_none() {
+        person_state.authority = *authority.key;
+    }
+
+    resize_account(
+        pda_account,
+        authority,
+        system_program,
+        BORSH_PERSON_ACCOUNT_SIZE,
+    )?;
+    {
+        let mut data = pda_account.data.borrow_mut();
+        data.fill(0);
+        person_state
+            .try_serialize(&mut &mut data[..])
+            .map_err(|_| DemoError::SizeOverflow)?;
+    }
+
+    ensure_borsh_person_descriptor(
+        program_id,
+        authority,
+        pda_account_descriptor,
+        system_program,
+    )
+}
+
+/// Closes one of the shared descriptor accounts. Descriptors are shared by every Person account
+/// of a type, so only the program's upgrade authority may remove them.
+pub fn close_descriptor(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+    check_person_descriptor(program_id, pda_account_descriptor)?;
+
+    close_account(pda_account_descriptor, authority)
+}
+
+/// Starts a schema upload: stamps the buffer the client created with the descriptor it is for,
+/// the encoding of the schema and the signer, who alone may write to and finalize it.
+pub fn begin_schema_upload(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    encoding: u8,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let buffer = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+    let format = check_person_descriptor(program_id, pda_account_descriptor)?;
+    let encoding =
+        SchemaEncoding::try_from(encoding).map_err(|_| ProgramError::InvalidInstructionData)?;
+    // A descriptor only ever describes its accounts in a schema of its own format.
+    let matches_format = match format {
+        DataFormat::Capnp => matches!(
+            encoding,
+            SchemaEncoding::CapnpText | SchemaEncoding::CapnpBinary
+        ),
+        DataFormat::Borsh => encoding == SchemaEncoding::Borsh,
+    };
+    if !matches_format {
+        msg!(
+            "{:?} descriptors can't hold a {:?} schema",
+            format,
+            encoding
+        );
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if buffer.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = buffer.data.borrow_mut();
+    // A new account is all zeroes. Anything else is a buffer already in use, or another account of
+    // the program.
+    let header = data
+        .get_mut(..SchemaBufferHeader::LEN)
+        .ok_or(DemoError::AccountTooShort)?;
+    if header.iter().any(|byte| *byte != 0) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    SchemaBufferHeader {
+        encoding,
+        descriptor: *pda_account_descriptor.key,
+        authority: *authority.key,
+    }
+    .pack(header)
+}
+
+/// Copies a piece of the schema into an upload buffer, and grows the descriptor towards the size
+/// the schema will need at finalize. [`cancel_schema_upload`] shrinks it back.
+pub fn write_schema_chunk(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    offset: u32,
+    bytes: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let buffer = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+    check_schema_buffer(program_id, buffer, pda_account_descriptor, authority)?;
+
+    let schema_len = {
+        let mut data = buffer.data.borrow_mut();
+        let schema = &mut data[SchemaBufferHeader::LEN..];
+        let start = offset as usize;
+        let end = start
+            .checked_add(bytes.len())
+            .filter(|end| *end <= schema.len())
+            .ok_or(DemoError::SizeOverflow)?;
+        schema[start..end].copy_from_slice(bytes);
+        schema.len()
+    };
+
+    // An account only grows by MAX_PERMITTED_DATA_INCREASE per instruction, too little for the
+    // schemas that need uploading, so every chunk takes the descriptor some of the way. The
+    // header still gives the length of the old schema, so readers ignore the added bytes.
+    let required = DescriptorHeader::LEN + schema_len;
+    let current = pda_account_descriptor.data_len();
+    if current < required {
+        resize_account(
+            pda_account_descriptor,
+            authority,
+            system_program,
+            required.min(current + MAX_PERMITTED_DATA_INCREASE),
+        )?;
+    }
+    Ok(())
+}
+
+/// Replaces the schema in the descriptor with the uploaded one once it matches `expected_hash`,
+/// and closes the buffer, refunding its rent to the authority.
+pub fn finalize_schema(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    expected_hash: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let buffer = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+    let header = check_schema_buffer(program_id, buffer, pda_account_descriptor, authority)?;
+    let expected_hash: [u8; 32] = expected_hash
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    {
+        let data = buffer.data.borrow();
+        let (_, schema) = read_schema_buffer(&data)?;
+        if hash(schema).to_bytes() != expected_hash {
+            msg!("Uploaded schema does not match the expected hash");
+            return Err(DemoError::SchemaHash.into());
+        }
+        {
+            let data = pda_account_descriptor.data.borrow();
+            let (current, stored) = read_descriptor(&data)?;
+            check_uploaded_schema(current.encoding, stored, header.encoding, schema)?;
+        }
+
+        // The header and the schema are replaced in one go, so the descriptor never holds a
+        // partial schema.
+        resize_account(
+            pda_account_descriptor,
+            authority,
+            system_program,
+            DescriptorHeader::LEN + schema.len(),
+        )?;
+        write_descriptor(
+            &mut pda_account_descriptor.data.borrow_mut(),
+            header.encoding,
+            schema,
+        )?;
+    }
+
+    close_account(buffer, authority)
+}
+
+/// Abandons a schema upload: closes the buffer and shrinks the descriptor back to the schema it
+/// still holds, refunding the rent of both to the authority.
+pub fn cancel_schema_upload(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    // Getting required accounts
+    let authority = next_account_info(accounts_iter)?;
+    let buffer = next_account_info(accounts_iter)?;
+    let pda_account_descriptor = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+    check_schema_buffer(program_id, buffer, pda_account_descriptor, authority)?;
+
+    // Writes grew the descriptor ahead of the schema, the header still gives the size it needs.
+    let size = {
+        let data = pda_account_descriptor.data.borrow();
+        let (header, _) = read_descriptor(&data)?;
+        header.account_size()
+    };
+    resize_account(pda_account_descriptor, authority, system_program, size)?;
+
+    close_account(buffer, authority)
+}
+
+/// Fails unless `schema`, uploaded in `encoding` to replace `current`, still describes the
+/// accounts of the Person descriptor. A compiled capnp schema has to read, and write the same way,
+/// the Persons the replaced schema and person_v2.capnp describe. Any other schema has to be one the
+/// program was built with.
+fn check_uploaded_schema(
+    current_encoding: SchemaEncoding,
+    current: &[u8],
+    encoding: SchemaEncoding,
+    schema: &[u8],
+) -> ProgramResult {
+    let known = match encoding {
+        SchemaEncoding::CapnpBinary => {
+            return check_compiled_person_schema(current_encoding, current, schema)
+        }
+        SchemaEncoding::CapnpText => Person::LEGACY_SCHEMAS
+            .iter()
+            .any(|&(known_encoding, known)| known_encoding == encoding && known == schema),
+        SchemaEncoding::Borsh => borsh_person_schema()? == schema,
+    };
+    if !known {
+        msg!(
+            "The program was not built with the uploaded {:?} schema",
+            encoding
+        );
+        return Err(DemoError::IncompatibleSchema.into());
+    }
+    Ok(())
+}
+
+/// Checks the compiled capnp `schema` against person_v2.capnp, which every Person the program
+/// writes follows, and against `current` if the descriptor holds another compiled schema.
+fn check_compiled_person_schema(
+    current_encoding: SchemaEncoding,
+    current: &[u8],
+    schema: &[u8],
+) -> ProgramResult {
+    let message =
+        serialize::read_message_from_flat_slice_no_alloc(&mut &schema[..], ReaderOptions::new())
+            .map_err(|_| DemoError::CapnpDecode)?;
+    let new = message
+        .get_root::<code_generator_request::Reader>()
+        .map_err(|_| DemoError::CapnpDecode)?;
+    let new_person = find_struct(new, "Person").ok_or_else(|| {
+        msg!("Uploaded schema has no Person struct");
+        DemoError::IncompatibleSchema
+    })?;
+
+    let mut replaced = vec![Person::SCHEMAS[0]];
+    if current_encoding == SchemaEncoding::CapnpBinary && current != Person::SCHEMAS[0] {
+        replaced.push(current);
+    }
+    for old in replaced {
+        let message =
+            serialize::read_message_from_flat_slice_no_alloc(&mut &old[..], ReaderOptions::new())
+                .map_err(|_| DemoError::CapnpDecode)?;
+        let old = message
+            .get_root::<code_generator_request::Reader>()
+            .map_err(|_| DemoError::CapnpDecode)?;
+        let old_person = find_struct(old, "Person").ok_or(DemoError::CapnpDecode)?;
+
+        let report = check_compatibility(old, old_person, new, new_person)
+            .map_err(|_| DemoError::CapnpDecode)?;
+        if !report.is_compatible() {
+            msg!("Uploaded schema is {}", report);
+            return Err(DemoError::IncompatibleSchema.into());
+        }
+    }
+    Ok(())
+}
+
+/// Fails unless `authority` signed and is the program's upgrade authority.
+fn check_upgrade_authority(
+    program_id: &Pubkey,
+    authority: &AccountInfo,
+    program_data: &AccountInfo,
+) -> ProgramResult {
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if upgrade_authority(program_id, program_data)?.ne(authority.key) {
+        msg!("Descriptor accounts can only be changed by the upgrade authority");
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
+
+/// Fails unless `descriptor` is one of the Person descriptors, created by this program, and
+/// returns the format of the accounts it describes.
+fn check_person_descriptor(
+    program_id: &Pubkey,
+    descriptor: &AccountInfo,
+) -> Result<DataFormat, ProgramError> {
+    let format = [DataFormat::Capnp, DataFormat::Borsh]
+        .into_iter()
+        .find(|format| {
+            find_descriptor_address(program_id, *format, PERSON_TYPE_NAME)
+                .0
+                .eq(descriptor.key)
+        })
+        .ok_or(DemoError::InvalidPda)?;
+    if descriptor.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(format)
+}
+
+/// Reads the header of an upload buffer, checking it was begun for `descriptor` by `authority`.
+fn check_schema_buffer(
+    program_id: &Pubkey,
+    buffer: &AccountInfo,
+    descriptor: &AccountInfo,
+    authority: &AccountInfo,
+) -> Result<SchemaBufferHeader, ProgramError> {
+    if buffer.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let header = SchemaBufferHeader::unpack(&buffer.data.borrow())?;
+    if header.descriptor.ne(descriptor.key) {
+        msg!(
+            "Schema buffer {} is for descriptor {}",
+            buffer.key,
+            header.descriptor
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+    if header.authority.ne(authority.key) {
+        return Err(DemoError::InvalidAuthority.into());
+    }
+    Ok(header)
+}
+
+/// Reads the upgrade authority out of the program's `ProgramData` account.
+fn upgrade_authority(
+    program_id: &Pubkey,
+    program_data: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let program_data_address = bpf_loader_upgradeable::get_program_data_address(program_id);
+    if program_data_address.ne(program_data.key)
+        || program_data.owner.ne(&bpf_loader_upgradeable::id())
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // UpgradeableLoaderState::ProgramData is bincode encoded as
+    // [tag u32 = 3][slot u64][Option<Pubkey>], so the authority starts at byte 13.
+    let data = program_data.data.borrow();
+    if data.len() < 45 || data[0..4] != [3, 0, 0, 0] || data[12] != 1 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(Pubkey::new_from_array(data[13..45].try_into().unwrap()))
+}
+
+/// Reallocs `account` to `new_size` and settles the rent difference with `funding_account`, which
+/// pays when the account grows and is refunded when it shrinks.
+pub(crate) fn resize_account<'a>(
+    account: &AccountInfo<'a>,
+    funding_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_size: usize,
+) -> ProgramResult {
+    if account.data_len() == new_size {
+        return Ok(());
+    }
+    msg!(
+        "Resizing {} from {} to {} bytes",
+        account.key,
+        account.data_len(),
+        new_size
+    );
+
+    let lamports_required = Rent::get()?.minimum_balance(new_size);
+    let lamports = account.lamports();
+    if lamports_required > lamports {
+        invoke(
+            &system_instruction::transfer(
+                funding_account.key,
+                account.key,
+                lamports_required - lamports,
+            ),
+            &[
+                funding_account.clone(),
+                account.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    } else if lamports > lamports_required {
+        // The program owns the account, so the excess can be moved out directly.
+        **funding_account.lamports.borrow_mut() = funding_account
+            .lamports()
+            .checked_add(lamports - lamports_required)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **account.lamports.borrow_mut() = lamports_required;
+    }
+
+    account.realloc(new_size, false)
+}
+
+/// Zeroes the account data and moves all of its lamports to `destination`.
+pub(crate) fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    account.data.borrow_mut().fill(0);
+
+    let lamports = account.lamports();
+    **destination.lamports.borrow_mut() = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account.lamports.borrow_mut() = 0;
+
+    Ok(())
+}
+
 pub fn instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let instruction = DemoInstruction::unpack(instruction_data)?;
-    msg!("Instruction: {:?}", instruction);
+    // The instruction data is a single segment DemoInstruction message (see
+    // capnp-models/src/instruction.capnp), read in place without copying it.
+    let mut data = instruction_data;
+    let message = serialize::read_message_from_flat_slice_no_alloc(&mut data, ReaderOptions::new())
+        .map_err(|_| DemoError::CapnpDecode)?;
+    let instruction = message
+        .get_root::<demo_instruction::Reader>()
+        .map_err(|_| DemoError::CapnpDecode)?;
 
-    match instruction {
-        DemoInstruction::InitializeCapnp { bump } => {
-            initialize_capnp(program_id, accounts, &bump);
+    // Only the variant is logged: the instruction itself may carry a whole schema chunk.
+    match instruction.which().map_err(|_| DemoError::CapnpDecode)? {
+        demo_instruction::InitializeCapnp(params) => {
+            msg!("Instruction: InitializeCapnp");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let person = params.get_person().map_err(|_| DemoError::CapnpDecode)?;
+            let encoding = if params.get_packed() {
+                MessageEncoding::Packed
+            } else {
+                MessageEncoding::Unpacked
+            };
+            initialize_capnp(program_id, accounts, params.get_bump(), person, encoding)?;
+        }
+        demo_instruction::DeserializeCapnp(()) => {
+            msg!("Instruction: DeserializeCapnp");
+            deserialize_capnpn_demo(program_id, accounts, instruction_data)?;
+        }
+        demo_instruction::InitializeBorsh(params) => {
+            msg!("Instruction: InitializeBorsh");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let person_state = params
+                .get_person_state()
+                .map_err(|_| DemoError::CapnpDecode)?;
+            initialize_borsh(program_id, accounts, params.get_bump(), person_state)?;
+        }
+        demo_instruction::DeserializeBorsh(()) => {
+            msg!("Instruction: DeserializeBorsh");
+            deserialize_borsh_demo(program_id, accounts, instruction_data)?;
+        }
+        demo_instruction::UpdateCapnp(params) => {
+            msg!("Instruction: UpdateCapnp");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let person = params.get_person().map_err(|_| DemoError::CapnpDecode)?;
+            update_capnp(program_id, accounts, params.get_bump(), person)?;
         }
-        DemoInstruction::DeserializeCapnpDemo => {
-            deserialize_capnpn_demo(program_id, accounts, instruction_data);
+        demo_instruction::Close(params) => {
+            msg!("Instruction: Close");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            close(program_id, accounts, params.get_bump())?;
         }
-        DemoInstruction::InitializeBorsh { bump } => {
-            initialize_borsh(program_id, accounts, &bump);
+        demo_instruction::CloseDescriptor(()) => {
+            msg!("Instruction: CloseDescriptor");
+            close_descriptor(program_id, accounts)?;
         }
-        DemoInstruction::DeserializeBorshDemo => {
-            deserialize_borsh_demo(program_id, accounts, instruction_data);
+        demo_instruction::MigratePerson(params) => {
+            msg!("Instruction: MigratePerson");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            migrate_person(program_id, accounts, params)?;
+        }
+        demo_instruction::SetAuthority(params) => {
+            msg!("Instruction: SetAuthority");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let new_authority = params
+                .get_new_authority()
+                .map_err(|_| DemoError::CapnpDecode)?;
+            set_authority(program_id, accounts, params.get_bump(), new_authority)?;
+        }
+        demo_instruction::ConvertBorshToCapnp(params) => {
+            msg!("Instruction: ConvertBorshToCapnp");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let encoding = if params.get_packed() {
+                MessageEncoding::Packed
+            } else {
+                MessageEncoding::Unpacked
+            };
+            convert_borsh_to_capnp(program_id, accounts, params.get_bump(), encoding)?;
+        }
+        demo_instruction::ConvertCapnpToBorsh(params) => {
+            msg!("Instruction: ConvertCapnpToBorsh");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            convert_capnp_to_borsh(program_id, accounts, params.get_bump())?;
+        }
+        demo_instruction::BeginSchemaUpload(params) => {
+            msg!("Instruction: BeginSchemaUpload");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            begin_schema_upload(program_id, accounts, params.get_encoding())?;
+        }
+        demo_instruction::WriteSchemaChunk(params) => {
+            msg!("Instruction: WriteSchemaChunk");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let bytes = params.get_bytes().map_err(|_| DemoError::CapnpDecode)?;
+            write_schema_chunk(program_id, accounts, params.get_offset(), bytes)?;
+        }
+        demo_instruction::FinalizeSchema(params) => {
+            msg!("Instruction: FinalizeSchema");
+            let params = params.map_err(|_| DemoError::CapnpDecode)?;
+            let hash = params.get_hash().map_err(|_| DemoError::CapnpDecode)?;
+            finalize_schema(program_id, accounts, hash)?;
+        }
+        demo_instruction::CancelSchemaUpload(()) => {
+            msg!("Instruction: CancelSchemaUpload");
+            cancel_schema_upload(program_id, accounts)?;
         }
-        _ => {}
     }
 
+    // Read by the benchmark in program/tests/benchmark.rs, built with the `benchmark` feature so
+    // other builds don't pay for the log. Failed instructions return above and never log it.
+    #[cfg(all(target_os = "solana", feature = "benchmark"))]
+    msg!("Heap used: {} bytes", heap_used());
+
     Ok(())
 }
 
+/// Bytes the default bump allocator has handed out so far. The allocator counts down from the end
+/// of the heap and keeps its position in the first word of the heap, 0 until it first allocates.
+#[cfg(all(target_os = "solana", feature = "benchmark"))]
+fn heap_used() -> usize {
+    use solana_program::entrypoint::{HEAP_LENGTH, HEAP_START_ADDRESS};
+
+    let heap_end = HEAP_START_ADDRESS as usize + HEAP_LENGTH;
+    // SAFETY: the runtime maps the heap at HEAP_START_ADDRESS for every program invocation.
+    let position = unsafe { *(HEAP_START_ADDRESS as *const usize) };
+    if position == 0 {
+        0
+    } else {
+        heap_end - position
+    }
+}
@@ -0,0 +1,89 @@
+//! The capnp Person account.
+//!
+//! [`PersonAccount`] checks an account once and then hands out Person readers that borrow from
+//! the account data. Changes go through a [`PersonBuilder`], which holds its own copy of the
+//! Person and writes it back with `store`. Handlers, and programs calling into this one, use these
+//! instead of slicing the account data themselves.
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::account::{PERSON_TYPE_NAME, PERSON_V1, PERSON_V2};
+use crate::capnp_account::{CapnpAccount, CapnpAccountData, CapnpBuilder};
+use crate::descriptor::SchemaEncoding;
+use crate::error::DemoError;
+use crate::{
+    person_capnp, person_v2_capnp, pubkey_field, SizePolicy, PERSON_SCHEMA, PERSON_SCHEMA_TEXTS,
+    PERSON_SCHEMA_V2, PERSON_SIZE_POLICY,
+};
+
+/// Capnp Person accounts, at `["customaddress", funding account]`.
+pub struct Person;
+
+impl CapnpAccount for Person {
+    type Owned = person_capnp::person::Owned;
+
+    const TYPE_NAME: &'static str = PERSON_TYPE_NAME;
+    // person_v2.capnp reads v1 accounts as well, so it is what new descriptors hold.
+    const SCHEMAS: &'static [&'static [u8]] = &[PERSON_SCHEMA_V2, PERSON_SCHEMA];
+    const SCHEMA_ENCODING: SchemaEncoding = SchemaEncoding::CapnpBinary;
+    const LEGACY_SCHEMAS: &'static [(SchemaEncoding, &'static [u8])] = &[
+        (SchemaEncoding::CapnpText, PERSON_SCHEMA_TEXTS[1]),
+        (SchemaEncoding::CapnpText, PERSON_SCHEMA_TEXTS[0]),
+    ];
+    const SCHEMA_VERSION: u8 = PERSON_V1;
+    const LATEST_SCHEMA_VERSION: u8 = PERSON_V2;
+    const SIZE_POLICY: SizePolicy = PERSON_SIZE_POLICY;
+
+    fn seeds(funding_account: &Pubkey) -> Vec<&[u8]> {
+        vec![&b"customaddress"[..], funding_account.as_ref()]
+    }
+}
+
+pub type PersonAccount<'a> = CapnpAccountData<'a, Person>;
+
+pub type PersonBuilder = CapnpBuilder<Person>;
+
+impl CapnpAccountData<'_, Person> {
+    /// The Person's authority, `None` for Persons written before authorities existed. Persons of
+    /// both versions keep it in the `authority` field of person_v2.capnp.
+    pub fn authority(&self) -> Result<Option<Pubkey>, ProgramError> {
+        let message = self.message()?;
+        let person = message.root::<person_v2_capnp::person::Owned>()?;
+        pubkey_field(person.has_authority(), person.get_authority())
+    }
+}
+
+impl CapnpBuilder<Person> {
+    /// A v1 Person holding these names and, if given, `authority`. The message is laid out as
+    /// person_v2.capnp from the start, since that is where the authority goes.
+    pub fn new_v1(
+        firstname: capnp::text::Reader,
+        lastname: capnp::text::Reader,
+        authority: Option<&Pubkey>,
+    ) -> Self {
+        let mut message = capnp::message::Builder::new_default();
+        let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+        person.set_firstname(firstname);
+        person.set_lastname(lastname);
+        if let Some(authority) = authority {
+            person.set_authority(&authority.to_bytes());
+        }
+        CapnpBuilder::from_message(PERSON_V1, message)
+    }
+
+    /// Makes `authority` the Person's authority, whichever version the Person is at.
+    pub fn set_authority(&mut self, authority: &Pubkey) -> Result<(), ProgramError> {
+        self.root_as::<person_v2_capnp::person::Owned>()?
+            .set_authority(&authority.to_bytes());
+        Ok(())
+    }
+
+    /// The Person, through person_v2.capnp. v1 Persons have to be migrated first, their version
+    /// byte would no longer match the layout otherwise.
+    pub fn person_v2(&mut self) -> Result<person_v2_capnp::person::Builder<'_>, ProgramError> {
+        if self.version() == PERSON_V1 {
+            return Err(DemoError::SchemaVersion.into());
+        }
+        self.root_as::<person_v2_capnp::person::Owned>()
+    }
+}
@@ -0,0 +1,69 @@
+//! Layout of schema buffer accounts.
+//!
+//! Schemas too big for one transaction reach a descriptor through a buffer: the client creates an
+//! account owned by the program with [`schema_buffer_size`] bytes, `BeginSchemaUpload` writes the
+//! [`SchemaBufferHeader`], `WriteSchemaChunk` fills in the schema piece by piece and
+//! `FinalizeSchema` copies it into the descriptor once it matches the expected hash.
+//! `CancelSchemaUpload` closes the buffer without touching the descriptor's schema.
+//!
+//! ```text
+//! [magic: 4][encoding: 1][reserved: 3][descriptor: 32][authority: 32][payload]
+//! ```
+//!
+//! The payload takes up the rest of the account, so its length is fixed when the buffer is
+//! created.
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::descriptor::SchemaEncoding;
+use crate::error::DemoError;
+
+/// First bytes of every schema buffer account.
+pub const SCHEMA_BUFFER_MAGIC: [u8; 4] = *b"SBUF";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchemaBufferHeader {
+    /// How the schema in the buffer is encoded, and will be in the descriptor.
+    pub encoding: SchemaEncoding,
+    /// Descriptor the schema replaces the schema of.
+    pub descriptor: Pubkey,
+    /// Upgrade authority that began the upload, the only signer that may continue it.
+    pub authority: Pubkey,
+}
+
+impl SchemaBufferHeader {
+    pub const LEN: usize = 4 + 1 + 3 + 32 + 32;
+
+    pub fn pack(&self, dst: &mut [u8]) -> Result<(), ProgramError> {
+        let dst = dst.get_mut(..Self::LEN).ok_or(DemoError::SizeOverflow)?;
+        dst[0..4].copy_from_slice(&SCHEMA_BUFFER_MAGIC);
+        dst[4] = self.encoding as u8;
+        dst[5..8].fill(0);
+        dst[8..40].copy_from_slice(self.descriptor.as_ref());
+        dst[40..72].copy_from_slice(self.authority.as_ref());
+        Ok(())
+    }
+
+    pub fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        if src.len() < Self::LEN || src[0..4] != SCHEMA_BUFFER_MAGIC {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(SchemaBufferHeader {
+            encoding: SchemaEncoding::try_from(src[4])?,
+            descriptor: Pubkey::new_from_array(src[8..40].try_into().unwrap()),
+            authority: Pubkey::new_from_array(src[40..72].try_into().unwrap()),
+        })
+    }
+}
+
+/// Size of a buffer account for a schema of `payload_len` bytes.
+pub fn schema_buffer_size(payload_len: usize) -> usize {
+    SchemaBufferHeader::LEN + payload_len
+}
+
+/// Reads a buffer account, returning its header and the schema bytes, written or not.
+pub fn read_schema_buffer(data: &[u8]) -> Result<(SchemaBufferHeader, &[u8]), ProgramError> {
+    let header = SchemaBufferHeader::unpack(data)?;
+    Ok((header, &data[SchemaBufferHeader::LEN..]))
+}
@@ -0,0 +1,226 @@
+mod common;
+
+use capnp::message::ReaderOptions;
+use capnp::serialize;
+use common::with_account;
+use solana_capnp_demo::account::{
+    capnp_message_encoding, capnp_schema_version, find_account_descriptor, load_borsh_person,
+    load_capnp_person, read_capnp_message, MessageEncoding, CAPNP_HEADER_LEN, INITIALIZED_OFFSET,
+    MESSAGE_ENCODING_OFFSET, PERSON_TYPE_NAME, PERSON_V1, PERSON_V2, SCHEMA_VERSION_OFFSET,
+};
+use solana_capnp_demo::descriptor::{find_descriptor_address, DataFormat};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::{person_capnp, SizePolicy};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+fn capnp_person_data() -> Vec<u8> {
+    capnp_person_data_with(MessageEncoding::Unpacked)
+}
+
+fn capnp_person_data_with(encoding: MessageEncoding) -> Vec<u8> {
+    common::capnp_person_data(None, PERSON_V1, encoding, SizePolicy::Exact)
+}
+
+fn borsh_person_data() -> Vec<u8> {
+    common::borsh_person_data(&Pubkey::new_unique())
+}
+
+fn capnp_error(program_id: &Pubkey, owner: &Pubkey, data: &mut [u8]) -> ProgramError {
+    with_account(owner, data, |account| {
+        load_capnp_person(program_id, account)
+            .map(|_| ())
+            .unwrap_err()
+    })
+}
+
+fn borsh_error(program_id: &Pubkey, owner: &Pubkey, data: &mut [u8]) -> ProgramError {
+    with_account(owner, data, |account| {
+        load_borsh_person(program_id, account)
+            .map(|_| ())
+            .unwrap_err()
+    })
+}
+
+#[test]
+fn loads_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let mut data = capnp_person_data();
+
+    with_account(&program_id, &mut data, |account| {
+        let data = load_capnp_person(&program_id, account).unwrap();
+        let reader = serialize::read_message_from_flat_slice_no_alloc(
+            &mut &data[CAPNP_HEADER_LEN..],
+            ReaderOptions::new(),
+        )
+        .unwrap();
+        let person = reader.get_root::<person_capnp::person::Reader>().unwrap();
+        assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+    });
+}
+
+#[test]
+fn reads_capnp_person_version() {
+    let mut data = capnp_person_data();
+    assert_eq!(capnp_schema_version(&data), PERSON_V1);
+
+    data[SCHEMA_VERSION_OFFSET] = PERSON_V2;
+    assert_eq!(capnp_schema_version(&data), PERSON_V2);
+}
+
+#[test]
+fn reads_capnp_person_in_either_encoding() {
+    for encoding in [MessageEncoding::Unpacked, MessageEncoding::Packed] {
+        let data = capnp_person_data_with(encoding);
+        assert_eq!(capnp_message_encoding(&data).unwrap(), encoding);
+
+        let message = read_capnp_message(&data).unwrap();
+        let person = message.get_root::<person_capnp::person::Reader>().unwrap();
+        assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+        assert_eq!(person.get_lastname().unwrap().to_str().unwrap(), "Proto");
+    }
+}
+
+#[test]
+fn packed_person_is_smaller() {
+    let unpacked = capnp_person_data_with(MessageEncoding::Unpacked);
+    let packed = capnp_person_data_with(MessageEncoding::Packed);
+
+    assert!(packed.len() < unpacked.len());
+}
+
+#[test]
+fn rejects_unknown_message_encoding() {
+    let mut data = capnp_person_data();
+    data[MESSAGE_ENCODING_OFFSET] = 7;
+
+    assert_eq!(
+        read_capnp_message(&data).map(|_| ()).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
+
+#[test]
+fn rejects_capnp_person_owned_by_another_program() {
+    let program_id = Pubkey::new_unique();
+    let mut data = capnp_person_data();
+
+    assert_eq!(
+        capnp_error(&program_id, &Pubkey::new_unique(), &mut data),
+        ProgramError::IncorrectProgramId
+    );
+}
+
+#[test]
+fn rejects_borsh_account_as_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let mut data = borsh_person_data();
+    data.resize(64, 0);
+
+    assert_eq!(
+        capnp_error(&program_id, &program_id, &mut data),
+        DemoError::WrongDiscriminator.into()
+    );
+}
+
+#[test]
+fn rejects_uninitialized_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let mut data = capnp_person_data();
+    data[INITIALIZED_OFFSET] = 0;
+
+    assert_eq!(
+        capnp_error(&program_id, &program_id, &mut data),
+        DemoError::Uninitialized.into()
+    );
+}
+
+#[test]
+fn rejects_truncated_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let mut data = capnp_person_data();
+
+    assert_eq!(
+        capnp_error(&program_id, &program_id, &mut data[..CAPNP_HEADER_LEN]),
+        DemoError::AccountTooShort.into()
+    );
+}
+
+#[test]
+fn loads_borsh_person() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = common::borsh_person_data(&authority);
+
+    let person_state = with_account(&program_id, &mut data, |account| {
+        load_borsh_person(&program_id, account)
+    })
+    .unwrap();
+    assert_eq!(person_state.first_name(), "John");
+    assert_eq!(person_state.last_name(), "Borsh");
+    assert_eq!(person_state.authority(), Some(authority));
+}
+
+#[test]
+fn rejects_borsh_person_owned_by_another_program() {
+    let program_id = Pubkey::new_unique();
+    let mut data = borsh_person_data();
+
+    assert_eq!(
+        borsh_error(&program_id, &Pubkey::new_unique(), &mut data),
+        ProgramError::IncorrectProgramId
+    );
+}
+
+#[test]
+fn rejects_capnp_account_as_borsh_person() {
+    let program_id = Pubkey::new_unique();
+    let mut data = capnp_person_data();
+
+    assert_eq!(
+        borsh_error(&program_id, &program_id, &mut data),
+        DemoError::WrongDiscriminator.into()
+    );
+}
+
+#[test]
+fn rejects_uninitialized_borsh_person() {
+    let program_id = Pubkey::new_unique();
+    let mut data = borsh_person_data();
+    data[INITIALIZED_OFFSET] = 0;
+
+    assert_eq!(
+        borsh_error(&program_id, &program_id, &mut data),
+        DemoError::Uninitialized.into()
+    );
+}
+
+#[test]
+fn capnp_and_borsh_person_have_separate_descriptors() {
+    let program_id = Pubkey::new_unique();
+    let capnp_descriptor = find_account_descriptor(&program_id, &capnp_person_data()).unwrap();
+    let borsh_descriptor = find_account_descriptor(&program_id, &borsh_person_data()).unwrap();
+
+    assert_eq!(
+        capnp_descriptor,
+        find_descriptor_address(&program_id, DataFormat::Capnp, PERSON_TYPE_NAME)
+    );
+    assert_eq!(
+        borsh_descriptor,
+        find_descriptor_address(&program_id, DataFormat::Borsh, PERSON_TYPE_NAME)
+    );
+    assert_ne!(capnp_descriptor.0, borsh_descriptor.0);
+}
+
+#[test]
+fn unknown_account_has_no_descriptor() {
+    let program_id = Pubkey::new_unique();
+
+    assert_eq!(
+        find_account_descriptor(&program_id, &[0; 32]).unwrap_err(),
+        DemoError::WrongDiscriminator.into()
+    );
+    assert_eq!(
+        find_account_descriptor(&program_id, &[0; 4]).unwrap_err(),
+        DemoError::AccountTooShort.into()
+    );
+}
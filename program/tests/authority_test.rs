@@ -0,0 +1,154 @@
+mod common;
+
+use common::{borsh_person_data, capnp_person_data, with_accounts};
+use solana_capnp_demo::account::{load_borsh_person, MessageEncoding, PERSON_V1};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::person::PersonAccount;
+use solana_capnp_demo::{close, person_capnp, set_authority, PERSON_SIZE_POLICY};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+#[test]
+fn authority_closes_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+
+    with_accounts(
+        &program_id,
+        &authority,
+        true,
+        &mut data,
+        false,
+        |accounts| {
+            close(&program_id, accounts, 0).unwrap();
+            assert_eq!(accounts[0].lamports(), 1_000_000);
+            assert_eq!(accounts[1].lamports(), 0);
+        },
+    );
+}
+
+#[test]
+fn other_signer_cannot_close_person() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let mut data = borsh_person_data(&authority);
+
+    let error = with_accounts(&program_id, &other, true, &mut data, false, |accounts| {
+        close(&program_id, accounts, 0).unwrap_err()
+    });
+    assert_eq!(error, DemoError::InvalidAuthority.into());
+}
+
+#[test]
+fn authority_has_to_sign() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+
+    let error = with_accounts(
+        &program_id,
+        &authority,
+        false,
+        &mut data,
+        false,
+        |accounts| close(&program_id, accounts, 0).unwrap_err(),
+    );
+    assert_eq!(error, ProgramError::MissingRequiredSignature);
+}
+
+#[test]
+fn set_authority_hands_off_borsh_person() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let mut data = borsh_person_data(&authority);
+
+    with_accounts(
+        &program_id,
+        &authority,
+        true,
+        &mut data,
+        false,
+        |accounts| {
+            set_authority(&program_id, accounts, 0, new_authority.as_ref()).unwrap();
+            let person_state = load_borsh_person(&program_id, &accounts[1]).unwrap();
+            assert_eq!(person_state.authority(), Some(new_authority));
+
+            // The previous authority lost access.
+            assert_eq!(
+                set_authority(&program_id, accounts, 0, authority.as_ref()).unwrap_err(),
+                DemoError::InvalidAuthority.into()
+            );
+        },
+    );
+}
+
+#[test]
+fn set_authority_hands_off_capnp_person() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+
+    with_accounts(
+        &program_id,
+        &authority,
+        true,
+        &mut data,
+        false,
+        |accounts| {
+            set_authority(&program_id, accounts, 0, new_authority.as_ref()).unwrap();
+
+            let person_account = PersonAccount::load(&program_id, &accounts[1]).unwrap();
+            assert_eq!(person_account.authority(), Ok(Some(new_authority)));
+            let message = person_account.message().unwrap();
+            let person = message.root::<person_capnp::person::Owned>().unwrap();
+            assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+        },
+    );
+}
+
+#[test]
+fn set_authority_needs_a_pubkey() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = borsh_person_data(&authority);
+
+    let error = with_accounts(
+        &program_id,
+        &authority,
+        true,
+        &mut data,
+        false,
+        |accounts| set_authority(&program_id, accounts, 0, &[1, 2, 3]).unwrap_err(),
+    );
+    assert_eq!(error, ProgramError::InvalidInstructionData);
+}
+
+#[test]
+fn legacy_borsh_person_has_no_authority() {
+    let program_id = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let mut data = borsh_person_data(&Pubkey::default());
+
+    with_accounts(&program_id, &signer, true, &mut data, false, |accounts| {
+        let person_state = load_borsh_person(&program_id, &accounts[1]).unwrap();
+        assert_eq!(person_state.authority(), None);
+    });
+}
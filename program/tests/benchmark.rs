@@ -0,0 +1,261 @@
+//! Runs the initialize and deserialize instructions of both formats in `ProgramTest` across a range
+//! of name lengths, and writes what each cost to a JSON report:
+//!
+//! ```text
+//! cargo test-sbf --features benchmark --test benchmark -- --nocapture
+//! ```
+//!
+//! The report goes to `target/benchmark/report.json`, or wherever `BENCHMARK_REPORT` points.
+//! Entries come in a fixed order and funding accounts from fixed seeds, so reports from two
+//! commits diff line by line.
+//!
+//! Without the `benchmark` feature the test is ignored, so plain test runs skip it and write no
+//! report; `cargo test --test benchmark -- --ignored` still runs it.
+//!
+//! Compute units and heap usage are only measured when the program runs as SBF, which
+//! `cargo test-sbf` arranges. Under `cargo test` the program runs natively, the report says
+//! `"runtime": "native"` and leaves both out; account sizes and rent are the same either way. Heap
+//! usage comes from a log the program only writes when built with the `benchmark` feature, at the
+//! end of successful instructions; without the feature `heap_bytes` is null.
+
+use borsh::BorshSerialize;
+use capnp::message::Builder;
+use capnp::serialize;
+use serde_json::{json, Value};
+use solana_capnp_demo::account::PERSON_TYPE_NAME;
+use solana_capnp_demo::descriptor::{find_descriptor_address, DataFormat};
+use solana_capnp_demo::instruction_capnp::demo_instruction;
+use solana_capnp_demo::{instruction, MAX_NAME_LEN};
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::signer::keypair::keypair_from_seed;
+use solana_sdk::transaction::Transaction;
+
+/// Lengths, in bytes, of the first and last name of the benchmarked Persons.
+const NAME_LENS: [usize; 4] = [1, 16, 32, MAX_NAME_LEN];
+
+#[derive(Clone, Copy)]
+enum Format {
+    CapnpUnpacked,
+    CapnpPacked,
+    Borsh,
+}
+
+impl Format {
+    fn name(&self) -> &'static str {
+        match self {
+            Format::CapnpUnpacked => "capnp",
+            Format::CapnpPacked => "capnp-packed",
+            Format::Borsh => "borsh",
+        }
+    }
+
+    fn data_format(&self) -> DataFormat {
+        match self {
+            Format::Borsh => DataFormat::Borsh,
+            _ => DataFormat::Capnp,
+        }
+    }
+
+    fn initialize(&self, bump: u8, firstname: &str, lastname: &str) -> Vec<u8> {
+        instruction_data(|instruction| match self {
+            Format::CapnpUnpacked | Format::CapnpPacked => {
+                let mut params = instruction.init_initialize_capnp();
+                params.set_bump(bump);
+                params.set_packed(matches!(self, Format::CapnpPacked));
+                let mut person = params.init_person();
+                person.set_firstname(firstname.into());
+                person.set_lastname(lastname.into());
+            }
+            Format::Borsh => {
+                let person_state = (
+                    false,
+                    firstname.to_string(),
+                    lastname.to_string(),
+                    Pubkey::default(),
+                )
+                    .try_to_vec()
+                    .unwrap();
+                let mut params = instruction.init_initialize_borsh();
+                params.set_bump(bump);
+                params.set_person_state(&person_state);
+            }
+        })
+    }
+
+    fn deserialize(&self) -> Vec<u8> {
+        instruction_data(|mut instruction| match self {
+            Format::Borsh => instruction.set_deserialize_borsh(()),
+            _ => instruction.set_deserialize_capnp(()),
+        })
+    }
+}
+
+fn instruction_data(build: impl FnOnce(demo_instruction::Builder)) -> Vec<u8> {
+    let mut message = Builder::new_default();
+    build(message.init_root::<demo_instruction::Builder>());
+    serialize::write_message_to_words(&message)
+}
+
+/// What one transaction cost.
+struct Run {
+    compute_units: u64,
+    heap_bytes: Option<u64>,
+}
+
+impl Run {
+    fn to_json(&self, sbf: bool) -> Value {
+        if sbf {
+            json!({
+                "compute_units": self.compute_units,
+                "heap_bytes": self.heap_bytes,
+            })
+        } else {
+            json!({})
+        }
+    }
+}
+
+/// Sends `data` to the program, signed by `funding`, with the funding account, the Person account,
+/// the descriptor and the system program.
+async fn run(
+    banks_client: &mut BanksClient,
+    program_id: &Pubkey,
+    funding: &Keypair,
+    person: &Pubkey,
+    descriptor: &Pubkey,
+    data: Vec<u8>,
+) -> Run {
+    let instruction = Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(funding.pubkey(), true),
+            AccountMeta::new(*person, false),
+            AccountMeta::new(*descriptor, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&funding.pubkey()),
+        &[funding],
+        recent_blockhash,
+    );
+
+    let outcome = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    let metadata = outcome.metadata.unwrap();
+    if let Err(error) = outcome.result {
+        panic!("{:?}\n{}", error, metadata.log_messages.join("\n"));
+    }
+
+    Run {
+        compute_units: metadata.compute_units_consumed,
+        heap_bytes: metadata.log_messages.iter().rev().find_map(|line| {
+            line.strip_prefix("Program log: Heap used: ")?
+                .strip_suffix(" bytes")?
+                .parse()
+                .ok()
+        }),
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "benchmark"), ignore = "run with --features benchmark")]
+async fn capnp_and_borsh_benchmark() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test =
+        ProgramTest::new("solana_capnp_demo", program_id, processor!(instruction));
+    let sbf = std::env::var("SBF_OUT_DIR").is_ok() || std::env::var("BPF_OUT_DIR").is_ok();
+
+    // Every case gets its own funding account, and with it its own Person address. Pubkeys end up
+    // in the messages, so they are the same on every run.
+    let formats = [Format::CapnpUnpacked, Format::CapnpPacked, Format::Borsh];
+    let cases: Vec<(Format, usize, Keypair)> = formats
+        .iter()
+        .flat_map(|format| NAME_LENS.iter().map(move |len| (*format, *len)))
+        .zip(1u8..)
+        .map(|((format, len), seed)| (format, len, keypair_from_seed(&[seed; 32]).unwrap()))
+        .collect();
+    // Each descriptor is created by a warm-up Person, so every measured initialize only checks it.
+    let warm_ups: Vec<(Format, usize, Keypair)> = [Format::CapnpUnpacked, Format::Borsh]
+        .iter()
+        .zip(101u8..)
+        .map(|(format, seed)| (*format, 1, keypair_from_seed(&[seed; 32]).unwrap()))
+        .collect();
+    for (_, _, funding) in warm_ups.iter().chain(&cases) {
+        program_test.add_account(
+            funding.pubkey(),
+            Account::new(1_000_000_000, 0, &system_program::id()),
+        );
+    }
+
+    let (mut banks_client, _, _) = program_test.start().await;
+    let rent = banks_client.get_rent().await.unwrap();
+
+    let mut entries = Vec::new();
+    for (index, (format, len, funding)) in warm_ups.iter().chain(&cases).enumerate() {
+        let (person, bump) = Pubkey::find_program_address(
+            &[b"customaddress", funding.pubkey().as_ref()],
+            &program_id,
+        );
+        let (descriptor, _) =
+            find_descriptor_address(&program_id, format.data_format(), PERSON_TYPE_NAME);
+        let name = "x".repeat(*len);
+
+        let initialize = run(
+            &mut banks_client,
+            &program_id,
+            funding,
+            &person,
+            &descriptor,
+            format.initialize(bump, &name, &name),
+        )
+        .await;
+        let deserialize = run(
+            &mut banks_client,
+            &program_id,
+            funding,
+            &person,
+            &descriptor,
+            format.deserialize(),
+        )
+        .await;
+        if index < warm_ups.len() {
+            continue;
+        }
+
+        let account = banks_client.get_account(person).await.unwrap().unwrap();
+        entries.push(json!({
+            "format": format.name(),
+            "name_len": len,
+            "account_bytes": account.data.len(),
+            "rent_lamports": rent.minimum_balance(account.data.len()),
+            "initialize": initialize.to_json(sbf),
+            "deserialize": deserialize.to_json(sbf),
+        }));
+    }
+
+    let report = json!({
+        "runtime": if sbf { "sbf" } else { "native" },
+        "cases": entries,
+    });
+    let report = serde_json::to_string_pretty(&report).unwrap();
+    println!("{}", report);
+
+    let path = std::env::var("BENCHMARK_REPORT")
+        .unwrap_or_else(|_| "target/benchmark/report.json".to_string());
+    let path = std::path::Path::new(&path);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).unwrap();
+    }
+    std::fs::write(path, report + "\n").unwrap();
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::capnp_account_data;
+use solana_capnp_demo::account::{
+    capnp_person_discriminator, MessageEncoding, SCHEMA_VERSION_OFFSET,
+};
+use solana_capnp_demo::capnp_account::{close, CapnpAccount, CapnpAccountData, CapnpBuilder};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::person::Person;
+use solana_capnp_demo::{person_v2_capnp, SizePolicy};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// A second account type, to check nothing in `capnp_account` is tied to Person.
+struct Address;
+
+impl CapnpAccount for Address {
+    type Owned = person_v2_capnp::address::Owned;
+
+    const TYPE_NAME: &'static str = "address";
+    const SCHEMAS: &'static [&'static [u8]] = &[b"struct Address {}"];
+
+    fn seeds(funding_account: &Pubkey) -> Vec<&[u8]> {
+        vec![&b"address"[..], funding_account.as_ref()]
+    }
+}
+
+/// An `Address` account holding `city`.
+fn address_data(city: &str) -> Vec<u8> {
+    let mut builder = CapnpBuilder::<Address>::new();
+    builder.root().unwrap().set_city(city.into());
+    capnp_account_data(
+        &builder,
+        Address::SCHEMA_VERSION,
+        MessageEncoding::Packed,
+        SizePolicy::Exact,
+    )
+}
+
+/// Runs `f` against an account of `program_id` holding `data` and an empty destination account.
+fn with_accounts<R>(
+    program_id: &Pubkey,
+    data: &mut [u8],
+    f: impl FnOnce(&AccountInfo, &AccountInfo) -> R,
+) -> R {
+    let destination = Pubkey::new_unique();
+    common::with_accounts(program_id, &destination, true, data, false, |accounts| {
+        f(&accounts[1], &accounts[0])
+    })
+}
+
+#[test]
+fn each_type_has_its_own_discriminator_and_descriptor() {
+    let program_id = Pubkey::new_unique();
+
+    assert_eq!(Person::discriminator(), capnp_person_discriminator());
+    assert_ne!(Address::discriminator(), Person::discriminator());
+    assert_ne!(
+        Address::descriptor_address(&program_id),
+        Person::descriptor_address(&program_id)
+    );
+}
+
+#[test]
+fn loads_any_capnp_account_type() {
+    let program_id = Pubkey::new_unique();
+    let mut data = address_data("Wordsworth");
+
+    with_accounts(&program_id, &mut data, |account, _| {
+        let address = CapnpAccountData::<Address>::load(&program_id, account).unwrap();
+        assert_eq!(address.version(), Address::SCHEMA_VERSION);
+        assert_eq!(address.encoding(), MessageEncoding::Packed);
+
+        let message = address.message().unwrap();
+        let city = message
+            .root::<person_v2_capnp::address::Owned>()
+            .unwrap()
+            .get_city()
+            .unwrap();
+        assert_eq!(city.to_str().unwrap(), "Wordsworth");
+
+        let error = CapnpAccountData::<Person>::load(&program_id, account)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(error, DemoError::WrongDiscriminator.into());
+    });
+}
+
+#[test]
+fn rejects_newer_schema_version() {
+    let program_id = Pubkey::new_unique();
+    let mut data = address_data("Wordsworth");
+    data[SCHEMA_VERSION_OFFSET] = Address::LATEST_SCHEMA_VERSION + 1;
+
+    let error = with_accounts(&program_id, &mut data, |account, _| {
+        CapnpAccountData::<Address>::load(&program_id, account)
+            .map(|_| ())
+            .unwrap_err()
+    });
+    assert_eq!(error, DemoError::SchemaVersion.into());
+}
+
+#[test]
+fn closes_only_its_own_type() {
+    let program_id = Pubkey::new_unique();
+    let mut data = address_data("Wordsworth");
+
+    with_accounts(&program_id, &mut data, |account, destination| {
+        assert_eq!(
+            close::<Person>(&program_id, account, destination).unwrap_err(),
+            ProgramError::from(DemoError::WrongDiscriminator)
+        );
+
+        close::<Address>(&program_id, account, destination).unwrap();
+        assert_eq!(destination.lamports(), 1_000_000);
+        assert_eq!(account.lamports(), 0);
+        assert!(account.data.borrow().iter().all(|byte| *byte == 0));
+    });
+}
@@ -0,0 +1,162 @@
+use capnp::message::ReaderOptions;
+use capnp::serialize;
+use capnp_models::{CapnpSchema, PERSON_SCHEMAS};
+use solana_capnp_demo::account::{MessageEncoding, PERSON_V2};
+use solana_capnp_demo::person::PersonBuilder;
+use solana_capnp_demo::{person_capnp, person_v2_capnp};
+
+/// person.capnp, declared in Rust.
+#[derive(CapnpSchema, Debug, PartialEq)]
+#[capnp(id = 0xd7f46c866337c03c, name = "Person")]
+struct PersonRecord {
+    #[capnp(ordinal = 0)]
+    firstname: String,
+    #[capnp(ordinal = 1)]
+    lastname: String,
+}
+
+/// The first four fields of person_v2.capnp, declared in a different order.
+#[derive(CapnpSchema, Debug, PartialEq)]
+struct PersonV2Record {
+    #[capnp(ordinal = 3)]
+    birth_year: u16,
+    #[capnp(ordinal = 2)]
+    email: String,
+    #[capnp(ordinal = 0)]
+    firstname: String,
+    #[capnp(ordinal = 1)]
+    lastname: String,
+}
+
+/// Fields that share data words.
+#[derive(CapnpSchema, Debug, PartialEq)]
+struct Mixed {
+    #[capnp(ordinal = 0)]
+    flag: bool,
+    #[capnp(ordinal = 1)]
+    small: u8,
+    #[capnp(ordinal = 2)]
+    medium: u16,
+    #[capnp(ordinal = 3)]
+    large: u32,
+    #[capnp(ordinal = 4)]
+    huge: u64,
+    #[capnp(ordinal = 5)]
+    other_flag: bool,
+}
+
+/// Schemas of a descriptor that publishes the derived Person.
+const DERIVED_SCHEMAS: &[&[u8]] = &[PersonRecord::SCHEMA.as_bytes()];
+
+fn captain() -> PersonRecord {
+    PersonRecord {
+        firstname: "Captain".to_string(),
+        lastname: "Proto".to_string(),
+    }
+}
+
+#[test]
+fn derives_the_person_schema() {
+    assert_eq!(PersonRecord::STRUCT_NAME, "Person");
+    assert!(PersonRecord::SCHEMA.starts_with("@0xd7f46c866337c03c;\n"));
+
+    // Apart from the imports, the derived schema is person.capnp itself.
+    let (_, declaration) = PersonRecord::SCHEMA.split_once("\n\n").unwrap();
+    assert!(PERSON_SCHEMAS[0].contains(declaration.trim_end()));
+    assert_eq!(DERIVED_SCHEMAS[0], PersonRecord::SCHEMA.as_bytes());
+
+    assert!(PersonV2Record::SCHEMA.contains("  birthYear @3 :UInt16;\n"));
+}
+
+#[test]
+fn reads_and_writes_generated_person() {
+    let bytes = serialize::write_message_to_words(&captain().to_message());
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let person = message.get_root::<person_capnp::person::Reader>().unwrap();
+    assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+
+    assert_eq!(PersonRecord::from_reader(person).unwrap(), captain());
+    assert_eq!(PersonRecord::read_root(&message).unwrap(), captain());
+}
+
+#[test]
+fn lays_out_data_fields_like_capnpc() {
+    let record = PersonV2Record {
+        birth_year: 1987,
+        email: "captain@proto.dev".to_string(),
+        firstname: "Captain".to_string(),
+        lastname: "Proto".to_string(),
+    };
+    let bytes = serialize::write_message_to_words(&record.to_message());
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let person = message
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap();
+    assert_eq!(person.get_birth_year(), 1987);
+    assert_eq!(
+        person.get_email().unwrap().to_str().unwrap(),
+        "captain@proto.dev"
+    );
+
+    let mixed = Mixed {
+        flag: true,
+        small: 0x11,
+        medium: 0x2222,
+        large: 0x3333_3333,
+        huge: 0x4444_4444_4444_4444,
+        other_flag: true,
+    };
+    assert_eq!(Mixed::STRUCT_SIZE.data, 2);
+    assert_eq!(Mixed::STRUCT_SIZE.pointers, 0);
+
+    // Segment table and root pointer, then the two data words: both flags in the first byte,
+    // `small`, `medium` and `large` in the rest of the first word and `huge` in the second.
+    let bytes = serialize::write_message_to_words(&mixed.to_message());
+    assert_eq!(bytes[16..20], [0b11, 0x11, 0x22, 0x22]);
+    assert_eq!(bytes[20..24], 0x3333_3333u32.to_le_bytes());
+    assert_eq!(bytes[24..32], 0x4444_4444_4444_4444u64.to_le_bytes());
+
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    assert_eq!(Mixed::read_root(&message).unwrap(), mixed);
+}
+
+#[test]
+fn writes_derived_person_through_the_account_builder() {
+    let mut message = capnp::message::Builder::new_default();
+    let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+    person.set_firstname("Captain".into());
+    person.set_email("captain@proto.dev".into());
+    person.init_address().set_city("Wordsworth".into());
+
+    let mut builder = PersonBuilder::from_message(PERSON_V2, message);
+    builder
+        .write(&PersonRecord {
+            firstname: "Commander".to_string(),
+            ..captain()
+        })
+        .unwrap();
+
+    let bytes = builder.encode(MessageEncoding::Unpacked).unwrap();
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let person = message
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap();
+    assert_eq!(
+        person.get_firstname().unwrap().to_str().unwrap(),
+        "Commander"
+    );
+    assert_eq!(
+        person.get_email().unwrap().to_str().unwrap(),
+        "captain@proto.dev"
+    );
+    assert_eq!(
+        person
+            .get_address()
+            .unwrap()
+            .get_city()
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "Wordsworth"
+    );
+}
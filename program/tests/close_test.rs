@@ -0,0 +1,204 @@
+mod common;
+
+use common::{borsh_person_data, capnp_person_data, with_accounts};
+use solana_capnp_demo::account::{MessageEncoding, PERSON_TYPE_NAME, PERSON_V1};
+use solana_capnp_demo::descriptor::{find_descriptor_address, DataFormat};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::{close, close_descriptor, PERSON_SIZE_POLICY};
+use solana_program::{
+    account_info::AccountInfo, bpf_loader_upgradeable, program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// The `ProgramData` account of `program_id`, with `upgrade_authority` if there is one.
+fn program_data(upgrade_authority: Option<&Pubkey>) -> Vec<u8> {
+    // UpgradeableLoaderState::ProgramData: [tag u32 = 3][slot u64][Option<Pubkey>].
+    let mut data = vec![3, 0, 0, 0];
+    data.extend(42u64.to_le_bytes());
+    match upgrade_authority {
+        Some(authority) => {
+            data.push(1);
+            data.extend(authority.to_bytes());
+        }
+        None => data.extend([0; 33]),
+    }
+    data
+}
+
+/// Runs `f` with the accounts of `CloseDescriptor`: `signer`, signing if `is_signer` and without
+/// lamports, the Capnp Person descriptor of `program_id` holding 1_000_000 lamports and the
+/// `ProgramData` account holding `program_data`.
+fn with_descriptor_accounts<R>(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    is_signer: bool,
+    program_data: &mut [u8],
+    f: impl FnOnce(&[AccountInfo]) -> R,
+) -> R {
+    let (descriptor_key, _) =
+        find_descriptor_address(program_id, DataFormat::Capnp, PERSON_TYPE_NAME);
+    let program_data_key = bpf_loader_upgradeable::get_program_data_address(program_id);
+    let (mut signer_lamports, mut descriptor_lamports, mut program_data_lamports) =
+        (0, 1_000_000, 1);
+    let (mut signer_data, mut descriptor_data) = (vec![], vec![7; 128]);
+
+    let accounts = [
+        AccountInfo::new(
+            signer,
+            is_signer,
+            true,
+            &mut signer_lamports,
+            &mut signer_data,
+            &solana_program::system_program::id(),
+            false,
+            0,
+        ),
+        AccountInfo::new(
+            &descriptor_key,
+            false,
+            true,
+            &mut descriptor_lamports,
+            &mut descriptor_data,
+            program_id,
+            false,
+            0,
+        ),
+        AccountInfo::new(
+            &program_data_key,
+            false,
+            false,
+            &mut program_data_lamports,
+            program_data,
+            &bpf_loader_upgradeable::id(),
+            false,
+            0,
+        ),
+    ];
+    f(&accounts)
+}
+
+#[test]
+fn close_refunds_the_rent_and_clears_the_account() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    for mut data in [
+        capnp_person_data(
+            Some(&authority),
+            PERSON_V1,
+            MessageEncoding::Packed,
+            PERSON_SIZE_POLICY,
+        ),
+        borsh_person_data(&authority),
+    ] {
+        with_accounts(
+            &program_id,
+            &authority,
+            true,
+            &mut data,
+            false,
+            |accounts| {
+                close(&program_id, accounts, 0).unwrap();
+                assert_eq!(accounts[0].lamports(), 1_000_000);
+                assert_eq!(accounts[1].lamports(), 0);
+            },
+        );
+        assert!(data.iter().all(|byte| *byte == 0));
+    }
+}
+
+#[test]
+fn close_leaves_the_account_alone_when_it_fails() {
+    let program_id = Pubkey::new_unique();
+    let (authority, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = borsh_person_data(&authority);
+    let original = data.clone();
+
+    with_accounts(&program_id, &other, true, &mut data, false, |accounts| {
+        assert_eq!(
+            close(&program_id, accounts, 0),
+            Err(DemoError::InvalidAuthority.into())
+        );
+        assert_eq!(accounts[0].lamports(), 0);
+        assert_eq!(accounts[1].lamports(), 1_000_000);
+    });
+    assert_eq!(data, original);
+
+    // Only accounts of the program close.
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+    let error = with_accounts(
+        &Pubkey::new_unique(),
+        &authority,
+        true,
+        &mut data,
+        false,
+        |accounts| close(&program_id, accounts, 0).unwrap_err(),
+    );
+    assert_eq!(error, ProgramError::IncorrectProgramId);
+}
+
+#[test]
+fn close_descriptor_refunds_the_upgrade_authority() {
+    let program_id = Pubkey::new_unique();
+    let upgrade_authority = Pubkey::new_unique();
+    let mut data = program_data(Some(&upgrade_authority));
+
+    with_descriptor_accounts(
+        &program_id,
+        &upgrade_authority,
+        true,
+        &mut data,
+        |accounts| {
+            close_descriptor(&program_id, accounts).unwrap();
+            assert_eq!(accounts[0].lamports(), 1_000_000);
+            assert_eq!(accounts[1].lamports(), 0);
+            assert!(accounts[1].data.borrow().iter().all(|byte| *byte == 0));
+        },
+    );
+}
+
+#[test]
+fn close_descriptor_needs_the_upgrade_authority() {
+    let program_id = Pubkey::new_unique();
+    let (upgrade_authority, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let mut data = program_data(Some(&upgrade_authority));
+    let errors = [
+        with_descriptor_accounts(&program_id, &other, true, &mut data, |accounts| {
+            close_descriptor(&program_id, accounts).unwrap_err()
+        }),
+        with_descriptor_accounts(
+            &program_id,
+            &upgrade_authority,
+            false,
+            &mut data,
+            |accounts| close_descriptor(&program_id, accounts).unwrap_err(),
+        ),
+    ];
+    assert_eq!(
+        errors,
+        [
+            ProgramError::IllegalOwner,
+            ProgramError::MissingRequiredSignature
+        ]
+    );
+
+    // A program without an upgrade authority keeps its descriptors.
+    let mut data = program_data(None);
+    let error = with_descriptor_accounts(
+        &program_id,
+        &upgrade_authority,
+        true,
+        &mut data,
+        |accounts| {
+            let error = close_descriptor(&program_id, accounts).unwrap_err();
+            assert_eq!(accounts[1].lamports(), 1_000_000);
+            error
+        },
+    );
+    assert_eq!(error, ProgramError::InvalidAccountData);
+}
@@ -0,0 +1,135 @@
+//! Account data the tests expect or start from, and `AccountInfo`s for the tests that call the
+//! program's functions directly, without `ProgramTest`.
+
+// Every test crate that declares `mod common` only uses some of these.
+#![allow(dead_code)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bryte_descriptor_state::states::Discriminator;
+use solana_capnp_demo::account::{
+    MessageEncoding, BORSH_PERSON_ACCOUNT_SIZE, CAPNP_HEADER_LEN, INITIALIZED_OFFSET,
+    MESSAGE_ENCODING_OFFSET, SCHEMA_VERSION_OFFSET,
+};
+use solana_capnp_demo::capnp_account::{CapnpAccount, CapnpBuilder};
+use solana_capnp_demo::person::PersonBuilder;
+use solana_capnp_demo::{PersonState, SizePolicy};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+/// An initialized `T` account holding what `builder` holds, in `encoding` and at schema
+/// `version`, sized by `size_policy`.
+pub fn capnp_account_data<T: CapnpAccount>(
+    builder: &CapnpBuilder<T>,
+    version: u8,
+    encoding: MessageEncoding,
+    size_policy: SizePolicy,
+) -> Vec<u8> {
+    let message = builder.encode(encoding).unwrap();
+
+    let mut data = vec![0; CAPNP_HEADER_LEN];
+    data[0..8].copy_from_slice(&T::discriminator());
+    data[INITIALIZED_OFFSET] = 1;
+    data[SCHEMA_VERSION_OFFSET] = version;
+    data[MESSAGE_ENCODING_OFFSET] = encoding as u8;
+    data.extend(message.iter());
+    data.resize(size_policy.account_size_for_len(message.len()), 0);
+    data
+}
+
+/// A capnp Person account for Captain Proto, owned by `authority` if there is one.
+pub fn capnp_person_data(
+    authority: Option<&Pubkey>,
+    version: u8,
+    encoding: MessageEncoding,
+    size_policy: SizePolicy,
+) -> Vec<u8> {
+    let builder = PersonBuilder::new_v1("Captain".into(), "Proto".into(), authority);
+    capnp_account_data(&builder, version, encoding, size_policy)
+}
+
+/// John Borsh, owned by `authority`.
+pub fn person_state(authority: &Pubkey) -> PersonState {
+    let bytes = (true, "John".to_string(), "Borsh".to_string(), *authority)
+        .try_to_vec()
+        .unwrap();
+    PersonState::try_from_slice(&bytes).unwrap()
+}
+
+/// A Borsh Person account holding [`person_state`], padded like the accounts the program creates.
+pub fn borsh_person_data(authority: &Pubkey) -> Vec<u8> {
+    let mut data = PersonState::DISCRIMINATOR.to_vec();
+    person_state(authority).serialize(&mut data).unwrap();
+    data.resize(BORSH_PERSON_ACCOUNT_SIZE, 0);
+    data
+}
+
+/// Runs `f` against an account holding `data` and owned by `owner`.
+pub fn with_account<R>(owner: &Pubkey, data: &mut [u8], f: impl FnOnce(&AccountInfo) -> R) -> R {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let account = AccountInfo::new(&key, false, true, &mut lamports, data, owner, false, 0);
+    f(&account)
+}
+
+/// Runs `f` with the accounts of a Person instruction: `signer`, signing if `is_signer` and
+/// without lamports, a Person account of `program_id` holding `data`, an empty descriptor if
+/// `with_descriptor`, and the system program.
+pub fn with_accounts<R>(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    is_signer: bool,
+    data: &mut [u8],
+    with_descriptor: bool,
+    f: impl FnOnce(&[AccountInfo]) -> R,
+) -> R {
+    let system_program_id = solana_program::system_program::id();
+    let (person_key, descriptor_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (mut signer_lamports, mut person_lamports) = (0, 1_000_000);
+    let (mut descriptor_lamports, mut system_lamports) = (0, 1);
+    let (mut signer_data, mut descriptor_data, mut system_data) = (vec![], vec![], vec![]);
+
+    let mut accounts = vec![
+        AccountInfo::new(
+            signer,
+            is_signer,
+            true,
+            &mut signer_lamports,
+            &mut signer_data,
+            &system_program_id,
+            false,
+            0,
+        ),
+        AccountInfo::new(
+            &person_key,
+            false,
+            true,
+            &mut person_lamports,
+            data,
+            program_id,
+            false,
+            0,
+        ),
+    ];
+    if with_descriptor {
+        accounts.push(AccountInfo::new(
+            &descriptor_key,
+            false,
+            true,
+            &mut descriptor_lamports,
+            &mut descriptor_data,
+            &system_program_id,
+            false,
+            0,
+        ));
+    }
+    accounts.push(AccountInfo::new(
+        &system_program_id,
+        false,
+        false,
+        &mut system_lamports,
+        &mut system_data,
+        &system_program_id,
+        true,
+        0,
+    ));
+    f(&accounts)
+}
@@ -0,0 +1,234 @@
+use capnp::message::{Builder, HeapAllocator, ReaderOptions};
+use capnp::serialize;
+use capnp_models::compatibility::{check_compatibility, find_struct, Change, Incompatibility};
+use capnp_models::schema_capnp::{code_generator_request, field, node};
+use capnp_models::PERSON_SCHEMA_REQUESTS;
+
+/// Person v2's request, with `edit` applied to its struct `name`.
+fn edited_person_v2(
+    name: &str,
+    edit: impl FnOnce(node::struct_::Builder),
+) -> Builder<HeapAllocator> {
+    let message = serialize::read_message(PERSON_SCHEMA_REQUESTS[1], ReaderOptions::new()).unwrap();
+    let request = message
+        .get_root::<code_generator_request::Reader>()
+        .unwrap();
+    let id = find_struct(request, name).unwrap();
+
+    let mut edited = Builder::new_default();
+    edited.set_root(request).unwrap();
+    let nodes = edited
+        .get_root::<code_generator_request::Builder>()
+        .unwrap()
+        .get_nodes()
+        .unwrap();
+    let index = request
+        .get_nodes()
+        .unwrap()
+        .iter()
+        .position(|node| node.get_id() == id)
+        .unwrap();
+    match nodes.get(index as u32).which().unwrap() {
+        node::Struct(struct_node) => edit(struct_node),
+        _ => unreachable!(),
+    }
+    edited
+}
+
+fn slot<'a>(
+    fields: &'a mut capnp::struct_list::Builder<'_, field::Owned>,
+    index: u32,
+) -> field::slot::Builder<'a> {
+    match fields.reborrow().get(index).which().unwrap() {
+        field::Slot(slot) => slot,
+        field::Group(_) => unreachable!(),
+    }
+}
+
+/// Checks the Person of v2 edited by `edit` against the unchanged v2.
+fn check_edited(name: &str, edit: impl FnOnce(node::struct_::Builder)) -> Vec<Incompatibility> {
+    let message = serialize::read_message(PERSON_SCHEMA_REQUESTS[1], ReaderOptions::new()).unwrap();
+    let old = message.get_root().unwrap();
+    let edited = edited_person_v2(name, edit);
+    let new = edited.get_root_as_reader().unwrap();
+    check_compatibility(
+        old,
+        find_struct(old, "Person").unwrap(),
+        new,
+        find_struct(new, "Person").unwrap(),
+    )
+    .unwrap()
+    .incompatibilities
+}
+
+fn incompatibility(path: &str, change: Change) -> Incompatibility {
+    Incompatibility {
+        path: path.to_string(),
+        change,
+    }
+}
+
+#[test]
+fn published_person_versions_only_grow() {
+    let v1 = serialize::read_message(PERSON_SCHEMA_REQUESTS[0], ReaderOptions::new()).unwrap();
+    let v2 = serialize::read_message(PERSON_SCHEMA_REQUESTS[1], ReaderOptions::new()).unwrap();
+    let v1 = v1.get_root::<code_generator_request::Reader>().unwrap();
+    let v2 = v2.get_root::<code_generator_request::Reader>().unwrap();
+    let v1_person = find_struct(v1, "Person").unwrap();
+    let v2_person = find_struct(v2, "Person").unwrap();
+    // The two versions are different files, so their Person IDs differ.
+    assert_ne!(v1_person, v2_person);
+
+    let upgrade = check_compatibility(v1, v1_person, v2, v2_person).unwrap();
+    assert!(upgrade.is_compatible(), "{}", upgrade);
+    assert_eq!(upgrade.to_string(), "compatible");
+    let unchanged = check_compatibility(v2, v2_person, v2, v2_person).unwrap();
+    assert!(unchanged.is_compatible(), "{}", unchanged);
+
+    // Going back drops fields and shrinks both sections.
+    let downgrade = check_compatibility(v2, v2_person, v1, v1_person).unwrap();
+    assert!(!downgrade.is_compatible());
+    assert_eq!(
+        downgrade.incompatibilities,
+        [
+            incompatibility(
+                "Person",
+                Change::DataSectionShrunk {
+                    old_words: 1,
+                    new_words: 0
+                }
+            ),
+            incompatibility(
+                "Person",
+                Change::PointerSectionShrunk {
+                    old_pointers: 5,
+                    new_pointers: 2
+                }
+            ),
+            incompatibility("Person.email", Change::FieldRemoved { ordinal: Some(2) }),
+            incompatibility(
+                "Person.birthYear",
+                Change::FieldRemoved { ordinal: Some(3) }
+            ),
+            incompatibility("Person.address", Change::FieldRemoved { ordinal: Some(4) }),
+            incompatibility(
+                "Person.authority",
+                Change::FieldRemoved { ordinal: Some(5) }
+            ),
+        ]
+    );
+    assert!(downgrade
+        .to_string()
+        .contains("\n  Person.birthYear: field @3 removed"));
+}
+
+#[test]
+fn renames_are_compatible() {
+    let incompatibilities = check_edited("Person", |person| {
+        let mut fields = person.get_fields().unwrap();
+        fields.reborrow().get(0).set_name("givenName".into());
+    });
+    assert!(incompatibilities.is_empty(), "{:?}", incompatibilities);
+}
+
+#[test]
+fn catches_renumbered_fields() {
+    let incompatibilities = check_edited("Person", |person| {
+        let mut fields = person.get_fields().unwrap();
+        fields.reborrow().get(0).get_ordinal().set_explicit(1);
+        fields.reborrow().get(1).get_ordinal().set_explicit(0);
+    });
+    assert_eq!(
+        incompatibilities,
+        [
+            incompatibility(
+                "Person.firstname",
+                Change::FieldRenumbered {
+                    old_ordinal: 0,
+                    new_ordinal: 1
+                }
+            ),
+            incompatibility(
+                "Person.lastname",
+                Change::FieldRenumbered {
+                    old_ordinal: 1,
+                    new_ordinal: 0
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn catches_changed_types_offsets_and_defaults() {
+    let incompatibilities = check_edited("Person", |person| {
+        let mut fields = person.get_fields().unwrap();
+        slot(&mut fields, 1).init_type().set_data(());
+        slot(&mut fields, 2).set_offset(7);
+        slot(&mut fields, 3).init_default_value().set_uint16(1900);
+    });
+    assert_eq!(
+        incompatibilities,
+        [
+            incompatibility(
+                "Person.lastname",
+                Change::TypeChanged {
+                    old: "Text".to_string(),
+                    new: "Data".to_string()
+                }
+            ),
+            incompatibility("Person.email", Change::OffsetChanged { old: 2, new: 7 }),
+            incompatibility("Person.birthYear", Change::DefaultChanged),
+        ]
+    );
+}
+
+#[test]
+fn catches_fields_moved_into_a_union() {
+    let incompatibilities = check_edited("Person", |person| {
+        let mut fields = person.get_fields().unwrap();
+        fields.reborrow().get(3).set_discriminant_value(1);
+    });
+    assert_eq!(
+        incompatibilities,
+        [incompatibility(
+            "Person.birthYear",
+            Change::DiscriminantChanged {
+                old: None,
+                new: Some(1)
+            }
+        )]
+    );
+}
+
+#[test]
+fn follows_struct_fields() {
+    let incompatibilities = check_edited("Address", |mut address| {
+        address.set_pointer_count(3);
+        let mut fields = address.get_fields().unwrap();
+        slot(&mut fields, 1)
+            .init_type()
+            .init_list()
+            .init_element_type()
+            .set_text(());
+    });
+    assert_eq!(
+        incompatibilities,
+        [
+            incompatibility(
+                "Person.address",
+                Change::PointerSectionShrunk {
+                    old_pointers: 4,
+                    new_pointers: 3
+                }
+            ),
+            incompatibility(
+                "Person.address.city",
+                Change::TypeChanged {
+                    old: "Text".to_string(),
+                    new: "List(Text)".to_string()
+                }
+            ),
+        ]
+    );
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use borsh::BorshSerialize;
+use capnp::message::ReaderOptions;
+use capnp::serialize;
+use common::{borsh_person_data, capnp_person_data, person_state, with_accounts};
+use solana_capnp_demo::account::{MessageEncoding, PERSON_V1, PERSON_V2};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::person::PersonBuilder;
+use solana_capnp_demo::{
+    capnp_to_person_state, convert_borsh_to_capnp, convert_capnp_to_borsh, person_capnp,
+    person_state_to_capnp, person_v2_capnp, PersonState, SizePolicy,
+};
+use solana_program::pubkey::Pubkey;
+
+/// Encodes `builder` and reads it back as a PersonState.
+fn through_capnp(builder: &PersonBuilder) -> PersonState {
+    let bytes = builder.encode(MessageEncoding::Unpacked).unwrap();
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let person = message
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap();
+    capnp_to_person_state(person).unwrap()
+}
+
+#[test]
+fn borsh_person_round_trips_through_capnp() {
+    let original = person_state(&Pubkey::new_unique());
+    let builder = person_state_to_capnp(&original).unwrap();
+
+    let bytes = builder.encode(MessageEncoding::Unpacked).unwrap();
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let person = message.get_root::<person_capnp::person::Reader>().unwrap();
+    assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "John");
+    assert_eq!(person.get_lastname().unwrap().to_str().unwrap(), "Borsh");
+    assert_eq!(builder.version(), PERSON_V1);
+
+    assert_eq!(
+        through_capnp(&builder).try_to_vec().unwrap(),
+        original.try_to_vec().unwrap()
+    );
+}
+
+#[test]
+fn capnp_person_round_trips_through_borsh() {
+    let authority = Pubkey::new_unique();
+    let original = PersonBuilder::new_v1("Captain".into(), "Proto".into(), Some(&authority));
+
+    let person_state = through_capnp(&original);
+    assert_eq!(person_state.authority(), Some(authority));
+
+    let converted = person_state_to_capnp(&person_state).unwrap();
+    for encoding in [MessageEncoding::Unpacked, MessageEncoding::Packed] {
+        assert_eq!(
+            converted.encode(encoding).unwrap(),
+            original.encode(encoding).unwrap()
+        );
+    }
+}
+
+#[test]
+fn person_without_authority_converts_without_one() {
+    let legacy = person_state(&Pubkey::default());
+    let builder = person_state_to_capnp(&legacy).unwrap();
+
+    let bytes = builder.encode(MessageEncoding::Unpacked).unwrap();
+    let message = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    assert!(!message
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap()
+        .has_authority());
+    assert_eq!(through_capnp(&builder).authority(), None);
+}
+
+#[test]
+fn v2_person_does_not_convert_to_borsh() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V2,
+        MessageEncoding::Unpacked,
+        SizePolicy::Exact,
+    );
+
+    let error = with_accounts(&program_id, &authority, true, &mut data, true, |accounts| {
+        convert_capnp_to_borsh(&program_id, accounts, 0).unwrap_err()
+    });
+    assert_eq!(error, DemoError::SchemaVersion.into());
+}
+
+#[test]
+fn only_the_authority_converts() {
+    let program_id = Pubkey::new_unique();
+    let (authority, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let mut data = borsh_person_data(&authority);
+    let error = with_accounts(&program_id, &other, true, &mut data, true, |accounts| {
+        convert_borsh_to_capnp(&program_id, accounts, 0, MessageEncoding::Unpacked).unwrap_err()
+    });
+    assert_eq!(error, DemoError::InvalidAuthority.into());
+
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        SizePolicy::Exact,
+    );
+    let error = with_accounts(&program_id, &other, true, &mut data, true, |accounts| {
+        convert_capnp_to_borsh(&program_id, accounts, 0).unwrap_err()
+    });
+    assert_eq!(error, DemoError::InvalidAuthority.into());
+}
@@ -0,0 +1,157 @@
+use capnp::message::ReaderOptions;
+use capnp::serialize;
+use capnp_models::PERSON_SCHEMA_REQUESTS;
+use solana_capnp_demo::descriptor::{
+    ensure_descriptor, read_descriptor, write_descriptor, DescriptorHeader, SchemaEncoding,
+    DESCRIPTOR_MAGIC,
+};
+use solana_capnp_demo::error::DemoError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+const SCHEMA: &[u8] = include_bytes!("../../capnp-models/src/person.capnp");
+
+/// Runs `ensure_descriptor` against a descriptor that already exists and holds `data`.
+fn ensure_existing(data: &mut [u8], encoding: SchemaEncoding, payloads: &[&[u8]]) -> ProgramResult {
+    let program_id = Pubkey::new_unique();
+    let system_program_id = system_program::id();
+    let (funding_key, descriptor_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (mut funding_lamports, mut descriptor_lamports, mut system_lamports) = (1, 1, 1);
+    let (mut funding_data, mut system_data) = (vec![], vec![]);
+
+    let funding = AccountInfo::new(
+        &funding_key,
+        true,
+        true,
+        &mut funding_lamports,
+        &mut funding_data,
+        &system_program_id,
+        false,
+        0,
+    );
+    let descriptor = AccountInfo::new(
+        &descriptor_key,
+        false,
+        true,
+        &mut descriptor_lamports,
+        data,
+        &program_id,
+        false,
+        0,
+    );
+    let system = AccountInfo::new(
+        &system_program_id,
+        false,
+        false,
+        &mut system_lamports,
+        &mut system_data,
+        &system_program_id,
+        true,
+        0,
+    );
+
+    ensure_descriptor(
+        &program_id,
+        &funding,
+        &descriptor,
+        &system,
+        &[b"descriptor"],
+        encoding,
+        payloads,
+    )
+}
+
+#[test]
+fn descriptor_round_trip() {
+    let mut data = vec![0; DescriptorHeader::LEN + SCHEMA.len()];
+    let header = write_descriptor(&mut data, SchemaEncoding::CapnpText, SCHEMA).unwrap();
+
+    assert_eq!(&data[0..4], &DESCRIPTOR_MAGIC);
+    assert_eq!(header.account_size(), data.len());
+
+    let (read_header, payload) = read_descriptor(&data).unwrap();
+    assert_eq!(read_header, header);
+    assert_eq!(read_header.encoding, SchemaEncoding::CapnpText);
+    assert_eq!(payload, SCHEMA);
+}
+
+#[test]
+fn compiled_schemas_read_in_place() {
+    let read = |mut bytes: &[u8]| {
+        serialize::read_message_from_flat_slice_no_alloc(&mut bytes, ReaderOptions::new())
+            .map(|_| ())
+    };
+    // Account data starts on a word, and so does the payload after the header.
+    let schema = PERSON_SCHEMA_REQUESTS[1];
+    let mut words = capnp::Word::allocate_zeroed_vec((DescriptorHeader::LEN + schema.len()) / 8);
+    let data = capnp::Word::words_to_bytes_mut(&mut words);
+    write_descriptor(data, SchemaEncoding::CapnpBinary, schema).unwrap();
+    let (_, payload) = read_descriptor(data).unwrap();
+
+    read(payload).unwrap();
+    for schema in PERSON_SCHEMA_REQUESTS {
+        read(schema).unwrap();
+    }
+}
+
+#[test]
+fn descriptor_rejects_tampered_payload() {
+    let mut data = vec![0; DescriptorHeader::LEN + SCHEMA.len()];
+    write_descriptor(&mut data, SchemaEncoding::CapnpText, SCHEMA).unwrap();
+    data[DescriptorHeader::LEN] ^= 1;
+
+    assert_eq!(
+        read_descriptor(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
+
+#[test]
+fn descriptor_needs_room_for_payload() {
+    let mut data = vec![0; DescriptorHeader::LEN + SCHEMA.len() - 1];
+
+    assert_eq!(
+        write_descriptor(&mut data, SchemaEncoding::CapnpText, SCHEMA).unwrap_err(),
+        ProgramError::from(DemoError::SizeOverflow)
+    );
+}
+
+#[test]
+fn existing_descriptor_is_reused() {
+    let mut data = vec![0; DescriptorHeader::LEN + SCHEMA.len()];
+    write_descriptor(&mut data, SchemaEncoding::CapnpText, SCHEMA).unwrap();
+    let before = data.clone();
+
+    ensure_existing(&mut data, SchemaEncoding::CapnpText, &[SCHEMA]).unwrap();
+    assert_eq!(data, before);
+}
+
+#[test]
+fn existing_descriptor_must_match_schema() {
+    let mut data = vec![0; DescriptorHeader::LEN + SCHEMA.len()];
+    write_descriptor(&mut data, SchemaEncoding::CapnpText, SCHEMA).unwrap();
+
+    assert_eq!(
+        ensure_existing(&mut data, SchemaEncoding::CapnpText, &[b"struct Other {}"]).unwrap_err(),
+        ProgramError::from(DemoError::DescriptorMismatch)
+    );
+    assert_eq!(
+        ensure_existing(&mut data, SchemaEncoding::CapnpBinary, &[SCHEMA]).unwrap_err(),
+        ProgramError::from(DemoError::DescriptorMismatch)
+    );
+}
+
+#[test]
+fn existing_descriptor_may_hold_any_accepted_schema() {
+    let mut data = vec![0; DescriptorHeader::LEN + SCHEMA.len()];
+    write_descriptor(&mut data, SchemaEncoding::CapnpText, SCHEMA).unwrap();
+
+    ensure_existing(
+        &mut data,
+        SchemaEncoding::CapnpText,
+        &[b"struct Newer {}", SCHEMA],
+    )
+    .unwrap();
+}
@@ -0,0 +1,95 @@
+//! Compares packed and unpacked capnp Person accounts. Run with `--nocapture` to see the report:
+//!
+//! ```text
+//! cargo test --test encoding_report -- --nocapture
+//! ```
+//!
+//! The report only covers sizes and rent, which don't depend on where the program runs. Compute
+//! units do, and only mean something under `cargo test-sbf`: the benchmark in `benchmark.rs`
+//! records what `DeserializeCapnp` costs on packed and unpacked accounts, next to Borsh.
+
+use capnp::message::{Builder, HeapAllocator};
+use solana_capnp_demo::account::{encode_capnp_message, MessageEncoding};
+use solana_capnp_demo::{person_v2_capnp, MAX_FIELD_LEN, MAX_NAME_LEN, PERSON_SIZE_POLICY};
+use solana_program::{hash::hash, pubkey::Pubkey, rent::Rent};
+
+/// A pubkey with random looking bytes, unlike `Pubkey::new_unique`, which is mostly zeros.
+fn authority() -> Pubkey {
+    Pubkey::new_from_array(hash(b"authority").to_bytes())
+}
+
+/// A v1 Person as the program writes it, laid out as person_v2.capnp for its authority.
+fn person_v1(firstname: &str, lastname: &str) -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+    person.set_firstname(firstname.into());
+    person.set_lastname(lastname.into());
+    person.set_authority(&authority().to_bytes());
+    message
+}
+
+fn person_v2(field: &str) -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+    person.set_firstname("Captain".into());
+    person.set_lastname("Proto".into());
+    person.set_authority(&authority().to_bytes());
+    person.set_email(field.into());
+    person.set_birth_year(1987);
+    let mut address = person.init_address();
+    address.set_street(field.into());
+    address.set_city(field.into());
+    address.set_postal_code(field.into());
+    address.set_country(field.into());
+    message
+}
+
+struct Row {
+    message_len: usize,
+    account_size: usize,
+    rent: u64,
+}
+
+fn measure(message: &Builder<HeapAllocator>, encoding: MessageEncoding) -> Row {
+    let bytes = encode_capnp_message(message, encoding).unwrap();
+    let account_size = PERSON_SIZE_POLICY.account_size_for_len(bytes.len());
+    Row {
+        message_len: bytes.len(),
+        account_size,
+        rent: Rent::default().minimum_balance(account_size),
+    }
+}
+
+#[test]
+fn packed_and_unpacked_person_report() {
+    let max_name = "x".repeat(MAX_NAME_LEN);
+    let max_field = "x".repeat(MAX_FIELD_LEN);
+    let samples = [
+        ("v1 short names", person_v1("Captain", "Proto")),
+        ("v1 max names", person_v1(&max_name, &max_name)),
+        ("v2 short fields", person_v2("Wordsworth")),
+        ("v2 max fields", person_v2(&max_field)),
+    ];
+
+    println!(
+        "{:<16} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12}",
+        "person", "msg", "packed", "account", "packed", "rent", "packed"
+    );
+    for (name, message) in &samples {
+        let unpacked = measure(message, MessageEncoding::Unpacked);
+        let packed = measure(message, MessageEncoding::Packed);
+        println!(
+            "{:<16} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12}",
+            name,
+            unpacked.message_len,
+            packed.message_len,
+            unpacked.account_size,
+            packed.account_size,
+            unpacked.rent,
+            packed.rent
+        );
+
+        assert!(packed.message_len <= unpacked.message_len);
+        assert!(packed.rent <= unpacked.rent);
+    }
+}
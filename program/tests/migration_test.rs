@@ -0,0 +1,115 @@
+use capnp::message::{Builder, HeapAllocator, ReaderOptions};
+use capnp::serialize;
+use solana_capnp_demo::instruction_capnp::migrate_person;
+use solana_capnp_demo::{migrate_person_message, person_capnp, person_v2_capnp, MAX_FIELD_LEN};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+fn person_v1() -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut person = message.init_root::<person_capnp::person::Builder>();
+    person.set_firstname("Captain".into());
+    person.set_lastname("Proto".into());
+    message
+}
+
+fn person_v2() -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+    person.set_firstname("Captain".into());
+    person.set_lastname("Proto".into());
+    person.set_email("captain@proto.dev".into());
+    person.set_birth_year(1987);
+    let mut address = person.init_address();
+    address.set_street("1 Segment Lane".into());
+    address.set_city("Wordsworth".into());
+    address.set_postal_code("8008".into());
+    address.set_country("Far Pointer".into());
+    message
+}
+
+fn migrate_params(email: &str) -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut params = message.init_root::<migrate_person::Builder>();
+    params.set_email(email.into());
+    params.set_birth_year(1987);
+    params.init_address().set_city("Wordsworth".into());
+    message
+}
+
+/// Serializes `message` the way it is stored in an account.
+fn to_bytes(message: &Builder<HeapAllocator>) -> Vec<u8> {
+    let mut data = Vec::new();
+    serialize::write_message(&mut data, message).unwrap();
+    data
+}
+
+#[test]
+fn old_reader_reads_v2_data() {
+    let data = to_bytes(&person_v2());
+    let reader = serialize::read_message(data.as_slice(), ReaderOptions::new()).unwrap();
+    let person = reader.get_root::<person_capnp::person::Reader>().unwrap();
+
+    assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+    assert_eq!(person.get_lastname().unwrap().to_str().unwrap(), "Proto");
+}
+
+#[test]
+fn new_reader_reads_v1_data() {
+    let data = to_bytes(&person_v1());
+    let reader = serialize::read_message(data.as_slice(), ReaderOptions::new()).unwrap();
+    let person = reader
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap();
+
+    assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+    assert_eq!(person.get_lastname().unwrap().to_str().unwrap(), "Proto");
+    assert_eq!(person.get_email().unwrap().to_str().unwrap(), "");
+    assert_eq!(person.get_birth_year(), 0);
+    assert!(!person.has_address());
+}
+
+#[test]
+fn migration_keeps_names_and_adds_v2_fields() {
+    let v1 = person_v1();
+    let params = migrate_params("captain@proto.dev");
+    let authority = Pubkey::new_unique();
+    let migrated = migrate_person_message(
+        v1.get_root_as_reader().unwrap(),
+        params.get_root_as_reader().unwrap(),
+        &authority,
+    )
+    .unwrap();
+
+    let data = to_bytes(&migrated);
+    let reader = serialize::read_message(data.as_slice(), ReaderOptions::new()).unwrap();
+    let person = reader
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap();
+    assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+    assert_eq!(person.get_lastname().unwrap().to_str().unwrap(), "Proto");
+    assert_eq!(
+        person.get_email().unwrap().to_str().unwrap(),
+        "captain@proto.dev"
+    );
+    assert_eq!(person.get_birth_year(), 1987);
+    assert_eq!(person.get_authority().unwrap(), authority.as_ref());
+    let address = person.get_address().unwrap();
+    assert_eq!(address.get_city().unwrap().to_str().unwrap(), "Wordsworth");
+}
+
+#[test]
+fn migration_rejects_oversized_fields() {
+    let v1 = person_v1();
+    let params = migrate_params(&"a".repeat(MAX_FIELD_LEN + 1));
+
+    assert_eq!(
+        migrate_person_message(
+            v1.get_root_as_reader().unwrap(),
+            params.get_root_as_reader().unwrap(),
+            &Pubkey::new_unique(),
+        )
+        .unwrap_err(),
+        ProgramError::InvalidInstructionData
+    );
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use capnp::message::{Builder, HeapAllocator, ReaderOptions};
+use capnp::serialize;
+use common::{capnp_account_data, with_account};
+use solana_capnp_demo::account::{MessageEncoding, PERSON_V1, PERSON_V2};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::person::{PersonAccount, PersonBuilder};
+use solana_capnp_demo::{person_capnp, person_v2_capnp, SizePolicy};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+fn person_v2() -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+    person.set_firstname("Captain".into());
+    person.set_lastname("Proto".into());
+    person.set_email("captain@proto.dev".into());
+    person.init_address().set_city("Wordsworth".into());
+    message
+}
+
+/// A capnp Person account holding `message`, written with schema `version`.
+fn account_data(
+    message: Builder<HeapAllocator>,
+    version: u8,
+    encoding: MessageEncoding,
+) -> Vec<u8> {
+    let builder = PersonBuilder::from_message(version, message);
+    capnp_account_data(&builder, version, encoding, SizePolicy::Exact)
+}
+
+#[test]
+fn reads_person_from_account() {
+    let program_id = Pubkey::new_unique();
+
+    for encoding in [MessageEncoding::Unpacked, MessageEncoding::Packed] {
+        let mut data = account_data(person_v2(), PERSON_V2, encoding);
+        with_account(&program_id, &mut data, |account| {
+            let person_account = PersonAccount::load(&program_id, account).unwrap();
+            assert_eq!(person_account.version(), PERSON_V2);
+            assert_eq!(person_account.encoding(), encoding);
+
+            let message = person_account.message().unwrap();
+            let person = message.root::<person_capnp::person::Owned>().unwrap();
+            assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+            let person = message.root::<person_v2_capnp::person::Owned>().unwrap();
+            assert_eq!(
+                person.get_email().unwrap().to_str().unwrap(),
+                "captain@proto.dev"
+            );
+        });
+    }
+}
+
+#[test]
+fn rejects_unknown_schema_version() {
+    let program_id = Pubkey::new_unique();
+    let mut data = account_data(person_v2(), 3, MessageEncoding::Unpacked);
+
+    let error = with_account(&program_id, &mut data, |account| {
+        PersonAccount::load(&program_id, account)
+            .map(|_| ())
+            .unwrap_err()
+    });
+    assert_eq!(error, DemoError::SchemaVersion.into());
+}
+
+#[test]
+fn builder_keeps_fields_it_does_not_touch() {
+    let program_id = Pubkey::new_unique();
+    let mut data = account_data(person_v2(), PERSON_V2, MessageEncoding::Unpacked);
+
+    let mut builder = with_account(&program_id, &mut data, |account| {
+        PersonAccount::load(&program_id, account)
+            .unwrap()
+            .builder()
+            .unwrap()
+    });
+    builder.root().unwrap().set_firstname("Commander".into());
+
+    let message = builder.encode(MessageEncoding::Unpacked).unwrap();
+    let reader = serialize::read_message(message.as_slice(), ReaderOptions::new()).unwrap();
+    let person = reader
+        .get_root::<person_v2_capnp::person::Reader>()
+        .unwrap();
+    assert_eq!(
+        person.get_firstname().unwrap().to_str().unwrap(),
+        "Commander"
+    );
+    assert_eq!(
+        person
+            .get_address()
+            .unwrap()
+            .get_city()
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "Wordsworth"
+    );
+}
+
+#[test]
+fn replaced_text_takes_no_space() {
+    let mut builder = PersonBuilder::from_message(PERSON_V2, person_v2());
+    let before = builder.encode(MessageEncoding::Unpacked).unwrap().len();
+
+    for _ in 0..8 {
+        builder.root().unwrap().set_lastname("Proto".into());
+    }
+    assert_eq!(
+        builder.encode(MessageEncoding::Unpacked).unwrap().len(),
+        before
+    );
+}
+
+#[test]
+fn v1_person_has_no_v2_builder() {
+    let mut builder = PersonBuilder::new();
+    assert_eq!(builder.version(), PERSON_V1);
+    builder.root().unwrap().set_firstname("Captain".into());
+
+    assert_eq!(
+        builder.person_v2().map(|_| ()).unwrap_err(),
+        ProgramError::from(DemoError::SchemaVersion)
+    );
+    let message = builder.encode(MessageEncoding::Unpacked).unwrap();
+    let reader = serialize::read_message(message.as_slice(), ReaderOptions::new()).unwrap();
+    let person = reader.get_root::<person_capnp::person::Reader>().unwrap();
+    assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+}
@@ -0,0 +1,1537 @@
+//! Runs every `DemoInstruction` end to end against the program loaded into `ProgramTest`, and
+//! checks the Person and descriptor accounts it leaves behind. No validator is needed: under
+//! `cargo test` the program runs natively, `cargo test-sbf` runs the same tests against the SBF
+//! build.
+
+mod common;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bryte_descriptor_state::states::Discriminator;
+use capnp::message::{Builder, ReaderOptions};
+use capnp::serialize;
+use capnp_models::compatibility::find_struct;
+use capnp_models::schema_capnp::{code_generator_request, node};
+use capnp_models::{PERSON_SCHEMAS, PERSON_SCHEMA_REQUESTS};
+use common::capnp_account_data;
+use solana_capnp_demo::account::{
+    read_capnp_message, MessageEncoding, BORSH_PERSON_ACCOUNT_SIZE, MESSAGE_ENCODING_OFFSET,
+    PERSON_TYPE_NAME, PERSON_V1, PERSON_V2, SCHEMA_VERSION_OFFSET,
+};
+use solana_capnp_demo::descriptor::{
+    find_descriptor_address, read_descriptor, write_descriptor, DataFormat, DescriptorHeader,
+    SchemaEncoding,
+};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::instruction_capnp::demo_instruction;
+use solana_capnp_demo::person::PersonBuilder;
+use solana_capnp_demo::schema_buffer::{schema_buffer_size, SchemaBufferHeader};
+use solana_capnp_demo::{
+    instruction, person_v2_capnp, PersonState, MAX_NAME_LEN, PERSON_SIZE_POLICY,
+};
+use solana_program::bpf_loader_upgradeable;
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_instruction, system_program};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::signer::keypair::keypair_from_seed;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+const LAMPORTS_PER_WALLET: u64 = 10_000_000_000;
+
+/// Borsh encoding of a client supplied PersonState.
+type PersonStateFields = (bool, String, String, Pubkey);
+
+fn instruction_data(build: impl FnOnce(demo_instruction::Builder)) -> Vec<u8> {
+    let mut message = Builder::new_default();
+    build(message.init_root::<demo_instruction::Builder>());
+    serialize::write_message_to_words(&message)
+}
+
+fn initialize_capnp(bump: u8, firstname: &str, lastname: &str, packed: bool) -> Vec<u8> {
+    instruction_data(|instruction| {
+        let mut params = instruction.init_initialize_capnp();
+        params.set_bump(bump);
+        params.set_packed(packed);
+        let mut person = params.init_person();
+        person.set_firstname(firstname.into());
+        person.set_lastname(lastname.into());
+    })
+}
+
+fn initialize_borsh(bump: u8, firstname: &str, lastname: &str) -> Vec<u8> {
+    let person_state: PersonStateFields = (
+        false,
+        firstname.to_string(),
+        lastname.to_string(),
+        Pubkey::default(),
+    );
+    instruction_data(|instruction| {
+        let mut params = instruction.init_initialize_borsh();
+        params.set_bump(bump);
+        params.set_person_state(&person_state.try_to_vec().unwrap());
+    })
+}
+
+fn update_capnp(bump: u8, firstname: &str, lastname: &str) -> Vec<u8> {
+    instruction_data(|instruction| {
+        let mut params = instruction.init_update_capnp();
+        params.set_bump(bump);
+        let mut person = params.init_person();
+        person.set_firstname(firstname.into());
+        person.set_lastname(lastname.into());
+    })
+}
+
+fn close(bump: u8) -> Vec<u8> {
+    instruction_data(|instruction| instruction.init_close().set_bump(bump))
+}
+
+fn migrate_person(bump: u8, email: &str, birth_year: u16, city: &str) -> Vec<u8> {
+    instruction_data(|instruction| {
+        let mut params = instruction.init_migrate_person();
+        params.set_bump(bump);
+        params.set_email(email.into());
+        params.set_birth_year(birth_year);
+        params.init_address().set_city(city.into());
+    })
+}
+
+fn set_authority(bump: u8, new_authority: &Pubkey) -> Vec<u8> {
+    instruction_data(|instruction| {
+        let mut params = instruction.init_set_authority();
+        params.set_bump(bump);
+        params.set_new_authority(new_authority.as_ref());
+    })
+}
+
+fn convert_borsh_to_capnp(bump: u8, packed: bool) -> Vec<u8> {
+    instruction_data(|instruction| {
+        let mut params = instruction.init_convert_borsh_to_capnp();
+        params.set_bump(bump);
+        params.set_packed(packed);
+    })
+}
+
+fn convert_capnp_to_borsh(bump: u8) -> Vec<u8> {
+    instruction_data(|instruction| instruction.init_convert_capnp_to_borsh().set_bump(bump))
+}
+
+fn begin_schema_upload(encoding: SchemaEncoding) -> Vec<u8> {
+    instruction_data(|instruction| {
+        instruction
+            .init_begin_schema_upload()
+            .set_encoding(encoding as u8)
+    })
+}
+
+fn write_schema_chunk(offset: u32, bytes: &[u8]) -> Vec<u8> {
+    instruction_data(|instruction| {
+        let mut params = instruction.init_write_schema_chunk();
+        params.set_offset(offset);
+        params.set_bytes(bytes);
+    })
+}
+
+fn finalize_schema(hash: &[u8]) -> Vec<u8> {
+    instruction_data(|instruction| instruction.init_finalize_schema().set_hash(hash))
+}
+
+fn cancel_schema_upload() -> Vec<u8> {
+    instruction_data(|mut instruction| instruction.set_cancel_schema_upload(()))
+}
+
+/// A compiled capnp schema too big to fit in one transaction: person_v2.capnp with one more field,
+/// `padding @6 :Data`, whose default takes 20 KB. It still reads every Person.
+fn big_schema() -> Vec<u8> {
+    let message = serialize::read_message(PERSON_SCHEMA_REQUESTS[1], ReaderOptions::new()).unwrap();
+    let request = message
+        .get_root::<code_generator_request::Reader>()
+        .unwrap();
+    let person_id = find_struct(request, "Person").unwrap();
+    let (index, fields) = request
+        .get_nodes()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .find_map(|(index, candidate)| match candidate.which().unwrap() {
+            node::Struct(person) if candidate.get_id() == person_id => {
+                Some((index, person.get_fields().unwrap()))
+            }
+            _ => None,
+        })
+        .unwrap();
+
+    let mut schema = Builder::new_default();
+    schema.set_root(request).unwrap();
+    let nodes = schema
+        .get_root::<code_generator_request::Builder>()
+        .unwrap()
+        .get_nodes()
+        .unwrap();
+    let mut person = match nodes.get(index as u32).which().unwrap() {
+        node::Struct(person) => person,
+        _ => unreachable!(),
+    };
+    person.set_pointer_count(6);
+    let mut new_fields = person.init_fields(fields.len() + 1);
+    for (index, field) in fields.iter().enumerate() {
+        new_fields.set_with_caveats(index as u32, field).unwrap();
+    }
+    let mut padding = new_fields.get(fields.len());
+    padding.set_name("padding".into());
+    padding.set_code_order(6);
+    padding.set_discriminant_value(0xffff);
+    padding.init_ordinal().set_explicit(6);
+    let mut slot = padding.init_slot();
+    slot.set_offset(5);
+    slot.init_type().set_data(());
+    slot.init_default_value().set_data(&[0xa5; 20_000]);
+    slot.set_had_explicit_default(true);
+    serialize::write_message_to_words(&schema)
+}
+
+/// Builds the message a capnp Person account holding these names and `authority` stores.
+fn person_builder(firstname: &str, lastname: &str, authority: Option<&Pubkey>) -> PersonBuilder {
+    match authority {
+        Some(authority) => {
+            PersonBuilder::new_v1(firstname.into(), lastname.into(), Some(authority))
+        }
+        // Persons from before authorities are plain person.capnp messages.
+        None => {
+            let mut builder = PersonBuilder::new();
+            let mut person = builder.root().unwrap();
+            person.set_firstname(firstname.into());
+            person.set_lastname(lastname.into());
+            builder
+        }
+    }
+}
+
+fn descriptor_data(encoding: SchemaEncoding, payload: &[u8]) -> Vec<u8> {
+    let mut data = vec![0; DescriptorHeader::new(encoding, payload).account_size()];
+    write_descriptor(&mut data, encoding, payload).unwrap();
+    data
+}
+
+/// Reads the PersonState of a Borsh Person account.
+fn borsh_person(account: &Account) -> PersonStateFields {
+    assert_eq!(&account.data[0..8], &PersonState::DISCRIMINATOR[..]);
+    PersonStateFields::deserialize(&mut &account.data[8..]).unwrap()
+}
+
+/// Checks that `account` is a well formed descriptor of the program in `encoding` and returns
+/// its payload.
+fn assert_descriptor(account: &Account, program_id: &Pubkey, encoding: SchemaEncoding) -> Vec<u8> {
+    assert_eq!(account.owner, *program_id);
+    let (header, payload) = read_descriptor(&account.data).unwrap();
+    assert_eq!(header, DescriptorHeader::new(encoding, payload));
+    assert_eq!(account.data.len(), header.account_size());
+    payload.to_vec()
+}
+
+/// What a transaction did.
+struct Outcome {
+    result: Result<(), TransactionError>,
+    logs: Vec<String>,
+}
+
+impl Outcome {
+    fn assert_ok(&self) {
+        if let Err(error) = &self.result {
+            panic!("{:?}\n{}", error, self.logs.join("\n"));
+        }
+    }
+
+    /// Whether the program logged a line containing `text`.
+    fn logged(&self, text: &str) -> bool {
+        self.logs
+            .iter()
+            .any(|line| line.starts_with("Program log: ") && line.contains(text))
+    }
+
+    /// Checks that the transaction's only instruction failed with `expected`.
+    fn assert_error(&self, expected: impl Into<ProgramError>) {
+        let expected = expected.into();
+        match &self.result {
+            Err(TransactionError::InstructionError(0, error)) => {
+                assert_eq!(ProgramError::try_from(error.clone()), Ok(expected))
+            }
+            result => panic!(
+                "expected {:?}, got {:?}\n{}",
+                expected,
+                result,
+                self.logs.join("\n")
+            ),
+        }
+    }
+}
+
+/// The upgrade authority the `ProgramData` account of every test names.
+fn upgrade_authority() -> Keypair {
+    keypair_from_seed(&[255; 32]).unwrap()
+}
+
+/// The program loaded into `ProgramTest`.
+struct Demo {
+    context: ProgramTestContext,
+    program_id: Pubkey,
+}
+
+impl Demo {
+    async fn start() -> Demo {
+        Demo::start_with(Pubkey::new_unique(), |_| {}).await
+    }
+
+    /// Starts the program `program_id` after `setup` added accounts of its own.
+    async fn start_with(program_id: Pubkey, setup: impl FnOnce(&mut ProgramTest)) -> Demo {
+        let mut program_test =
+            ProgramTest::new("solana_capnp_demo", program_id, processor!(instruction));
+
+        // UpgradeableLoaderState::ProgramData, bincode encoded: [tag u32 = 3][slot u64]
+        // [Option<Pubkey>].
+        let upgrade_authority = upgrade_authority();
+        let mut program_data = vec![3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        program_data.extend(upgrade_authority.pubkey().to_bytes());
+        program_test.add_account(
+            bpf_loader_upgradeable::get_program_data_address(&program_id),
+            Account {
+                lamports: 1_000_000_000,
+                data: program_data,
+                owner: bpf_loader_upgradeable::id(),
+                ..Account::default()
+            },
+        );
+        program_test.add_account(
+            upgrade_authority.pubkey(),
+            Account::new(LAMPORTS_PER_WALLET, 0, &system_program::id()),
+        );
+        setup(&mut program_test);
+
+        Demo {
+            context: program_test.start_with_context().await,
+            program_id,
+        }
+    }
+
+    /// A new wallet, funded by the payer.
+    async fn wallet(&mut self) -> Keypair {
+        let wallet = Keypair::new();
+        let payer = &self.context.payer;
+        let transaction = Transaction::new_signed_with_payer(
+            &[system_instruction::transfer(
+                &payer.pubkey(),
+                &wallet.pubkey(),
+                LAMPORTS_PER_WALLET,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            self.context.last_blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+        wallet
+    }
+
+    fn person_address(&self, funding: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"customaddress", funding.as_ref()], &self.program_id)
+    }
+
+    fn descriptor_address(&self, format: DataFormat) -> Pubkey {
+        find_descriptor_address(&self.program_id, format, PERSON_TYPE_NAME).0
+    }
+
+    async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+    }
+
+    /// Sends `data` with `accounts`, signed by `signer`. The payer pays the fee, so `signer` only
+    /// pays what the instruction itself takes.
+    async fn send(
+        &mut self,
+        signer: &Keypair,
+        data: Vec<u8>,
+        accounts: Vec<AccountMeta>,
+    ) -> Outcome {
+        let instruction = Instruction::new_with_bytes(self.program_id, &data, accounts);
+        let payer = &self.context.payer;
+        let recent_blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, signer],
+            recent_blockhash,
+        );
+
+        let outcome = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await
+            .unwrap();
+        Outcome {
+            result: outcome.result,
+            logs: outcome
+                .metadata
+                .map(|metadata| metadata.log_messages)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Sends `data` with the accounts of the initialize, deserialize, migrate and convert
+    /// instructions: `signer`, the Person account of `funding`, the descriptor of `format` and
+    /// the system program.
+    async fn send_with_descriptor(
+        &mut self,
+        signer: &Keypair,
+        funding: &Pubkey,
+        format: DataFormat,
+        data: Vec<u8>,
+    ) -> Outcome {
+        let (person, _) = self.person_address(funding);
+        let accounts = vec![
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new(person, false),
+            AccountMeta::new(self.descriptor_address(format), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        self.send(signer, data, accounts).await
+    }
+
+    /// Sends `data` with the accounts of `UpdateCapnp` and `SetAuthority`, or of `Close` without
+    /// the system program: `signer`, the Person account of `funding` and the system program.
+    async fn send_to_person(
+        &mut self,
+        signer: &Keypair,
+        funding: &Pubkey,
+        data: Vec<u8>,
+        with_system_program: bool,
+    ) -> Outcome {
+        let (person, _) = self.person_address(funding);
+        let mut accounts = vec![
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new(person, false),
+        ];
+        if with_system_program {
+            accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+        }
+        self.send(signer, data, accounts).await
+    }
+
+    async fn initialize_capnp(&mut self, funding: &Keypair, packed: bool) -> Outcome {
+        let (_, bump) = self.person_address(&funding.pubkey());
+        let data = initialize_capnp(bump, "Captain", "Proto", packed);
+        self.send_with_descriptor(funding, &funding.pubkey(), DataFormat::Capnp, data)
+            .await
+    }
+
+    async fn initialize_borsh(&mut self, funding: &Keypair) -> Outcome {
+        let (_, bump) = self.person_address(&funding.pubkey());
+        let data = initialize_borsh(bump, "John", "Borsh");
+        self.send_with_descriptor(funding, &funding.pubkey(), DataFormat::Borsh, data)
+            .await
+    }
+
+    async fn close_descriptor(&mut self, signer: &Keypair, format: DataFormat) -> Outcome {
+        let accounts = vec![
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new(self.descriptor_address(format), false),
+            AccountMeta::new_readonly(
+                bpf_loader_upgradeable::get_program_data_address(&self.program_id),
+                false,
+            ),
+        ];
+        let data = instruction_data(|mut instruction| instruction.set_close_descriptor(()));
+        self.send(signer, data, accounts).await
+    }
+
+    /// Creates an account owned by the program, with room for a schema of `schema_len` bytes.
+    async fn create_schema_buffer(&mut self, schema_len: usize) -> Keypair {
+        let buffer = Keypair::new();
+        let size = schema_buffer_size(schema_len);
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let payer = &self.context.payer;
+        let transaction = Transaction::new_signed_with_payer(
+            &[system_instruction::create_account(
+                &payer.pubkey(),
+                &buffer.pubkey(),
+                rent.minimum_balance(size),
+                size as u64,
+                &self.program_id,
+            )],
+            Some(&payer.pubkey()),
+            &[payer, &buffer],
+            self.context.last_blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+        buffer
+    }
+
+    /// Sends `data` with the accounts of the schema upload instructions: `signer`, `buffer`, the
+    /// descriptor of `format`, the `ProgramData` account and the system program.
+    async fn send_to_schema_buffer(
+        &mut self,
+        signer: &Keypair,
+        buffer: &Pubkey,
+        format: DataFormat,
+        data: Vec<u8>,
+    ) -> Outcome {
+        let accounts = vec![
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new(*buffer, false),
+            AccountMeta::new(self.descriptor_address(format), false),
+            AccountMeta::new_readonly(
+                bpf_loader_upgradeable::get_program_data_address(&self.program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        self.send(signer, data, accounts).await
+    }
+
+    /// Writes `schema` into `buffer`, `chunk_len` bytes per transaction.
+    async fn write_schema(
+        &mut self,
+        signer: &Keypair,
+        buffer: &Pubkey,
+        schema: &[u8],
+        chunk_len: usize,
+    ) {
+        for (index, chunk) in schema.chunks(chunk_len).enumerate() {
+            let data = write_schema_chunk((index * chunk_len) as u32, chunk);
+            self.send_to_schema_buffer(signer, buffer, DataFormat::Capnp, data)
+                .await
+                .assert_ok();
+        }
+    }
+}
+
+#[tokio::test]
+async fn initialize_capnp_writes_person_and_descriptor() {
+    let mut demo = Demo::start().await;
+    let rent = demo.context.banks_client.get_rent().await.unwrap();
+
+    for packed in [false, true] {
+        let funding = demo.wallet().await;
+        demo.initialize_capnp(&funding, packed).await.assert_ok();
+
+        let encoding = if packed {
+            MessageEncoding::Packed
+        } else {
+            MessageEncoding::Unpacked
+        };
+        let builder = person_builder("Captain", "Proto", Some(&funding.pubkey()));
+        let (person, _) = demo.person_address(&funding.pubkey());
+        let account = demo.account(&person).await.unwrap();
+        assert_eq!(account.owner, demo.program_id);
+        assert_eq!(
+            account.data,
+            capnp_account_data(&builder, PERSON_V1, encoding, PERSON_SIZE_POLICY)
+        );
+        assert_eq!(account.lamports, rent.minimum_balance(account.data.len()));
+    }
+
+    // The first initialize created the descriptor with person_v2.capnp, which reads v1 Persons,
+    // compiled.
+    let descriptor = demo.descriptor_address(DataFormat::Capnp);
+    let descriptor = demo.account(&descriptor).await.unwrap();
+    let payload = assert_descriptor(&descriptor, &demo.program_id, SchemaEncoding::CapnpBinary);
+    assert_eq!(payload, PERSON_SCHEMA_REQUESTS[1]);
+}
+
+#[tokio::test]
+async fn initialize_capnp_rejects_bad_requests() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    let (person, bump) = demo.person_address(&funding.pubkey());
+
+    // A bump that doesn't derive the Person address.
+    let wrong_bump = (0..bump)
+        .rev()
+        .find(|bump| {
+            Pubkey::create_program_address(
+                &[b"customaddress", funding.pubkey().as_ref(), &[*bump]],
+                &demo.program_id,
+            )
+            .is_ok()
+        })
+        .unwrap();
+    let data = initialize_capnp(wrong_bump, "Captain", "Proto", false);
+    demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_error(DemoError::InvalidPda);
+
+    let long_name = "x".repeat(MAX_NAME_LEN + 1);
+    for (firstname, lastname) in [("", "Proto"), ("Captain", long_name.as_str())] {
+        let data = initialize_capnp(bump, firstname, lastname, false);
+        demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Capnp, data)
+            .await
+            .assert_error(ProgramError::InvalidInstructionData);
+    }
+
+    // The capnp Person needs the capnp descriptor.
+    let data = initialize_capnp(bump, "Captain", "Proto", false);
+    demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Borsh, data)
+        .await
+        .assert_error(DemoError::InvalidPda);
+
+    demo.send(&funding, vec![1, 2, 3], vec![])
+        .await
+        .assert_error(DemoError::CapnpDecode);
+    assert!(demo.account(&person).await.is_none());
+}
+
+#[tokio::test]
+async fn initialize_capnp_checks_an_existing_descriptor() {
+    let program_id = Pubkey::new_unique();
+    let descriptor = find_descriptor_address(&program_id, DataFormat::Capnp, PERSON_TYPE_NAME).0;
+    let mut demo = Demo::start_with(program_id, |program_test| {
+        program_test.add_account(
+            descriptor,
+            Account {
+                lamports: 1_000_000_000,
+                data: descriptor_data(SchemaEncoding::CapnpText, b"struct Other {}"),
+                owner: program_id,
+                ..Account::default()
+            },
+        );
+    })
+    .await;
+
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false)
+        .await
+        .assert_error(DemoError::DescriptorMismatch);
+}
+
+#[tokio::test]
+async fn deserialize_reads_both_formats() {
+    let mut demo = Demo::start().await;
+    let (capnp, borsh) = (demo.wallet().await, demo.wallet().await);
+    demo.initialize_capnp(&capnp, true).await.assert_ok();
+    demo.initialize_borsh(&borsh).await.assert_ok();
+
+    let data = instruction_data(|mut instruction| instruction.set_deserialize_capnp(()));
+    let outcome = demo
+        .send_with_descriptor(&capnp, &capnp.pubkey(), DataFormat::Capnp, data)
+        .await;
+    outcome.assert_ok();
+    assert!(outcome.logged("Captain") && outcome.logged("Proto"));
+
+    let data = instruction_data(|mut instruction| instruction.set_deserialize_borsh(()));
+    let outcome = demo
+        .send_with_descriptor(&borsh, &borsh.pubkey(), DataFormat::Borsh, data)
+        .await;
+    outcome.assert_ok();
+    assert!(outcome.logged("\"John\" \"Borsh\""));
+
+    // Each instruction reads its own format only.
+    let data = instruction_data(|mut instruction| instruction.set_deserialize_capnp(()));
+    demo.send_with_descriptor(&borsh, &borsh.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_error(DemoError::WrongDiscriminator);
+}
+
+#[tokio::test]
+async fn initialize_borsh_writes_person_and_descriptor() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_borsh(&funding).await.assert_ok();
+
+    let (person, _) = demo.person_address(&funding.pubkey());
+    let account = demo.account(&person).await.unwrap();
+    assert_eq!(account.owner, demo.program_id);
+    assert_eq!(account.data.len(), BORSH_PERSON_ACCOUNT_SIZE);
+    assert_eq!(
+        borsh_person(&account),
+        (
+            true,
+            "John".to_string(),
+            "Borsh".to_string(),
+            funding.pubkey()
+        )
+    );
+
+    let descriptor = demo.descriptor_address(DataFormat::Borsh);
+    let descriptor = demo.account(&descriptor).await.unwrap();
+    assert_descriptor(&descriptor, &demo.program_id, SchemaEncoding::Borsh);
+
+    // A second Borsh Person shares the descriptor.
+    let other = demo.wallet().await;
+    let (_, bump) = demo.person_address(&other.pubkey());
+    let data = initialize_borsh(bump, "John", "");
+    demo.send_with_descriptor(&other, &other.pubkey(), DataFormat::Borsh, data)
+        .await
+        .assert_error(ProgramError::InvalidInstructionData);
+    demo.initialize_borsh(&other).await.assert_ok();
+}
+
+#[tokio::test]
+async fn update_capnp_rewrites_names_for_the_authority() {
+    let mut demo = Demo::start().await;
+    let (funding, other) = (demo.wallet().await, demo.wallet().await);
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let (person, bump) = demo.person_address(&funding.pubkey());
+
+    demo.send_to_person(
+        &other,
+        &funding.pubkey(),
+        update_capnp(bump, "Jack", "Other"),
+        true,
+    )
+    .await
+    .assert_error(DemoError::InvalidAuthority);
+    demo.send_to_person(
+        &funding,
+        &funding.pubkey(),
+        update_capnp(bump, "", "Proto"),
+        true,
+    )
+    .await
+    .assert_error(ProgramError::InvalidInstructionData);
+
+    // Names long enough for the account to grow.
+    let (firstname, lastname) = ("Commander".repeat(7), "Proto".repeat(12));
+    demo.send_to_person(
+        &funding,
+        &funding.pubkey(),
+        update_capnp(bump, &firstname, &lastname),
+        true,
+    )
+    .await
+    .assert_ok();
+
+    let account = demo.account(&person).await.unwrap();
+    assert_eq!(account.data[SCHEMA_VERSION_OFFSET], PERSON_V1);
+    assert!(account.data.len() > 128);
+    let message = read_capnp_message(&account.data).unwrap();
+    let stored = message.root::<person_v2_capnp::person::Owned>().unwrap();
+    assert_eq!(stored.get_firstname().unwrap().to_str().unwrap(), firstname);
+    assert_eq!(stored.get_lastname().unwrap().to_str().unwrap(), lastname);
+    assert_eq!(stored.get_authority().unwrap(), funding.pubkey().as_ref());
+    let rent = demo.context.banks_client.get_rent().await.unwrap();
+    assert_eq!(account.lamports, rent.minimum_balance(account.data.len()));
+}
+
+#[tokio::test]
+async fn update_capnp_grows_and_shrinks_the_account() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let (person, bump) = demo.person_address(&funding.pubkey());
+    let rent = demo.context.banks_client.get_rent().await.unwrap();
+    let original = demo.account(&person).await.unwrap();
+    let balance = demo.account(&funding.pubkey()).await.unwrap().lamports;
+
+    let (firstname, lastname) = ("Commander".repeat(7), "Proto".repeat(12));
+    demo.send_to_person(
+        &funding,
+        &funding.pubkey(),
+        update_capnp(bump, &firstname, &lastname),
+        true,
+    )
+    .await
+    .assert_ok();
+
+    let grown = demo.account(&person).await.unwrap();
+    let builder = person_builder(&firstname, &lastname, Some(&funding.pubkey()));
+    assert_eq!(
+        grown.data,
+        capnp_account_data(
+            &builder,
+            PERSON_V1,
+            MessageEncoding::Unpacked,
+            PERSON_SIZE_POLICY
+        )
+    );
+    assert_eq!(grown.lamports, rent.minimum_balance(grown.data.len()));
+    // The authority paid for the growth.
+    assert_eq!(
+        demo.account(&funding.pubkey()).await.unwrap().lamports,
+        balance - (grown.lamports - original.lamports)
+    );
+
+    demo.send_to_person(
+        &funding,
+        &funding.pubkey(),
+        update_capnp(bump, "Captain", "Proto"),
+        true,
+    )
+    .await
+    .assert_ok();
+
+    // Back to the size and rent of the original, with what the growth cost refunded.
+    let shrunk = demo.account(&person).await.unwrap();
+    assert_eq!(shrunk.data, original.data);
+    assert_eq!(shrunk.lamports, original.lamports);
+    assert_eq!(
+        demo.account(&funding.pubkey()).await.unwrap().lamports,
+        balance
+    );
+}
+
+#[tokio::test]
+async fn update_capnp_claims_a_person_without_authority() {
+    let program_id = Pubkey::new_unique();
+    let funding = keypair_from_seed(&[7; 32]).unwrap();
+    let (person, bump) =
+        Pubkey::find_program_address(&[b"customaddress", funding.pubkey().as_ref()], &program_id);
+    let builder = person_builder("Captain", "Proto", None);
+    let mut demo = Demo::start_with(program_id, |program_test| {
+        program_test.add_account(
+            funding.pubkey(),
+            Account::new(LAMPORTS_PER_WALLET, 0, &system_program::id()),
+        );
+        program_test.add_account(
+            person,
+            Account {
+                lamports: 1_000_000_000,
+                data: capnp_account_data(
+                    &builder,
+                    PERSON_V1,
+                    MessageEncoding::Unpacked,
+                    PERSON_SIZE_POLICY,
+                ),
+                owner: program_id,
+                ..Account::default()
+            },
+        );
+    })
+    .await;
+
+    // Without a stored authority, only the wallet the address was derived from may sign.
+    let other = demo.wallet().await;
+    demo.send_to_person(
+        &other,
+        &funding.pubkey(),
+        update_capnp(bump, "Jack", "Other"),
+        true,
+    )
+    .await
+    .assert_error(DemoError::InvalidAuthority);
+    demo.send_to_person(
+        &funding,
+        &funding.pubkey(),
+        update_capnp(bump, "Captain", "Proto"),
+        true,
+    )
+    .await
+    .assert_ok();
+
+    let account = demo.account(&person).await.unwrap();
+    let message = read_capnp_message(&account.data).unwrap();
+    let stored = message.root::<person_v2_capnp::person::Owned>().unwrap();
+    assert_eq!(stored.get_authority().unwrap(), funding.pubkey().as_ref());
+}
+
+#[tokio::test]
+async fn v2_person_from_before_authorities_updates_and_closes() {
+    let program_id = Pubkey::new_unique();
+    let funding = keypair_from_seed(&[8; 32]).unwrap();
+    let (person, bump) =
+        Pubkey::find_program_address(&[b"customaddress", funding.pubkey().as_ref()], &program_id);
+    // What MigratePerson wrote before authorities existed: person_v2.capnp had these fields at
+    // the same ordinals, `authority` only came after them.
+    let mut message = Builder::new_default();
+    {
+        let mut migrated = message.init_root::<person_v2_capnp::person::Builder>();
+        migrated.set_firstname("Captain".into());
+        migrated.set_lastname("Proto".into());
+        migrated.set_email("captain@proto.dev".into());
+        migrated.set_birth_year(1987);
+        migrated.init_address().set_city("Wordsworth".into());
+    }
+    let builder = PersonBuilder::from_message(PERSON_V2, message);
+    let mut demo = Demo::start_with(program_id, |program_test| {
+        program_test.add_account(
+            funding.pubkey(),
+            Account::new(LAMPORTS_PER_WALLET, 0, &system_program::id()),
+        );
+        program_test.add_account(
+            person,
+            Account {
+                lamports: 1_000_000_000,
+                data: capnp_account_data(
+                    &builder,
+                    PERSON_V2,
+                    MessageEncoding::Unpacked,
+                    PERSON_SIZE_POLICY,
+                ),
+                owner: program_id,
+                ..Account::default()
+            },
+        );
+    })
+    .await;
+
+    demo.send_to_person(
+        &funding,
+        &funding.pubkey(),
+        update_capnp(bump, "Commander", "Proto"),
+        true,
+    )
+    .await
+    .assert_ok();
+
+    let account = demo.account(&person).await.unwrap();
+    assert_eq!(account.data[SCHEMA_VERSION_OFFSET], PERSON_V2);
+    let message = read_capnp_message(&account.data).unwrap();
+    let stored = message.root::<person_v2_capnp::person::Owned>().unwrap();
+    assert_eq!(
+        stored.get_firstname().unwrap().to_str().unwrap(),
+        "Commander"
+    );
+    assert_eq!(
+        stored.get_email().unwrap().to_str().unwrap(),
+        "captain@proto.dev"
+    );
+    assert_eq!(stored.get_birth_year(), 1987);
+    assert_eq!(
+        stored
+            .get_address()
+            .unwrap()
+            .get_city()
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "Wordsworth"
+    );
+    assert_eq!(stored.get_authority().unwrap(), funding.pubkey().as_ref());
+
+    demo.send_to_person(&funding, &funding.pubkey(), close(bump), false)
+        .await
+        .assert_ok();
+    assert!(demo.account(&person).await.is_none());
+}
+
+#[tokio::test]
+async fn close_returns_rent_to_the_authority() {
+    let mut demo = Demo::start().await;
+    let (capnp, borsh, other) = (
+        demo.wallet().await,
+        demo.wallet().await,
+        demo.wallet().await,
+    );
+    demo.initialize_capnp(&capnp, false).await.assert_ok();
+    demo.initialize_borsh(&borsh).await.assert_ok();
+
+    for funding in [&capnp, &borsh] {
+        let (person, bump) = demo.person_address(&funding.pubkey());
+        demo.send_to_person(&other, &funding.pubkey(), close(bump), false)
+            .await
+            .assert_error(DemoError::InvalidAuthority);
+
+        let rent = demo.account(&person).await.unwrap().lamports;
+        let balance = demo.account(&funding.pubkey()).await.unwrap().lamports;
+        demo.send_to_person(funding, &funding.pubkey(), close(bump), false)
+            .await
+            .assert_ok();
+
+        assert!(demo.account(&person).await.is_none());
+        assert_eq!(
+            demo.account(&funding.pubkey()).await.unwrap().lamports,
+            balance + rent
+        );
+    }
+}
+
+#[tokio::test]
+async fn close_descriptor_needs_the_upgrade_authority() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let descriptor = demo.descriptor_address(DataFormat::Capnp);
+
+    demo.close_descriptor(&funding, DataFormat::Capnp)
+        .await
+        .assert_error(ProgramError::IllegalOwner);
+
+    let upgrade_authority = upgrade_authority();
+    // The Borsh descriptor was never created, so the program doesn't own it.
+    demo.close_descriptor(&upgrade_authority, DataFormat::Borsh)
+        .await
+        .assert_error(ProgramError::IncorrectProgramId);
+
+    let rent = demo.account(&descriptor).await.unwrap().lamports;
+    let balance = demo
+        .account(&upgrade_authority.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    demo.close_descriptor(&upgrade_authority, DataFormat::Capnp)
+        .await
+        .assert_ok();
+
+    assert!(demo.account(&descriptor).await.is_none());
+    assert_eq!(
+        demo.account(&upgrade_authority.pubkey())
+            .await
+            .unwrap()
+            .lamports,
+        balance + rent
+    );
+}
+
+#[tokio::test]
+async fn migrate_person_moves_person_and_descriptor_to_v2() {
+    // A descriptor created before person_v2.capnp existed, when descriptors held schema text.
+    // Persons still initialize against it.
+    let program_id = Pubkey::new_unique();
+    let descriptor = find_descriptor_address(&program_id, DataFormat::Capnp, PERSON_TYPE_NAME).0;
+    let mut demo = Demo::start_with(program_id, |program_test| {
+        program_test.add_account(
+            descriptor,
+            Account {
+                lamports: 1_000_000_000,
+                data: descriptor_data(SchemaEncoding::CapnpText, PERSON_SCHEMAS[0].as_bytes()),
+                owner: program_id,
+                ..Account::default()
+            },
+        );
+    })
+    .await;
+    let (funding, other) = (demo.wallet().await, demo.wallet().await);
+    demo.initialize_capnp(&funding, true).await.assert_ok();
+    let (person, bump) = demo.person_address(&funding.pubkey());
+
+    let data = migrate_person(bump, "captain@proto.dev", 1987, "Wordsworth");
+    demo.send_with_descriptor(&other, &funding.pubkey(), DataFormat::Capnp, data.clone())
+        .await
+        .assert_error(DemoError::InvalidAuthority);
+    demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_ok();
+
+    let account = demo.account(&person).await.unwrap();
+    assert_eq!(account.data[SCHEMA_VERSION_OFFSET], PERSON_V2);
+    assert_eq!(
+        account.data[MESSAGE_ENCODING_OFFSET],
+        MessageEncoding::Packed as u8
+    );
+    let message = read_capnp_message(&account.data).unwrap();
+    let migrated = message.root::<person_v2_capnp::person::Owned>().unwrap();
+    assert_eq!(
+        migrated.get_firstname().unwrap().to_str().unwrap(),
+        "Captain"
+    );
+    assert_eq!(migrated.get_authority().unwrap(), funding.pubkey().as_ref());
+    assert_eq!(
+        migrated.get_email().unwrap().to_str().unwrap(),
+        "captain@proto.dev"
+    );
+    assert_eq!(migrated.get_birth_year(), 1987);
+    assert_eq!(
+        migrated
+            .get_address()
+            .unwrap()
+            .get_city()
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "Wordsworth"
+    );
+
+    let descriptor = demo.account(&descriptor).await.unwrap();
+    let payload = assert_descriptor(&descriptor, &program_id, SchemaEncoding::CapnpBinary);
+    assert_eq!(payload, PERSON_SCHEMA_REQUESTS[1]);
+
+    // A v2 Person doesn't migrate again.
+    let data = migrate_person(bump, "captain@proto.dev", 1988, "Wordsworth");
+    demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_error(DemoError::SchemaVersion);
+}
+
+#[tokio::test]
+async fn set_authority_hands_off_both_formats() {
+    let mut demo = Demo::start().await;
+    let (capnp, borsh, new_authority) = (
+        demo.wallet().await,
+        demo.wallet().await,
+        demo.wallet().await,
+    );
+    demo.initialize_capnp(&capnp, false).await.assert_ok();
+    demo.initialize_borsh(&borsh).await.assert_ok();
+
+    for funding in [&capnp, &borsh] {
+        let (person, bump) = demo.person_address(&funding.pubkey());
+        let data = set_authority(bump, &new_authority.pubkey());
+        demo.send_to_person(&new_authority, &funding.pubkey(), data.clone(), true)
+            .await
+            .assert_error(DemoError::InvalidAuthority);
+        demo.send_to_person(funding, &funding.pubkey(), data, true)
+            .await
+            .assert_ok();
+
+        let account = demo.account(&person).await.unwrap();
+        if funding.pubkey() == capnp.pubkey() {
+            let message = read_capnp_message(&account.data).unwrap();
+            let stored = message.root::<person_v2_capnp::person::Owned>().unwrap();
+            assert_eq!(stored.get_firstname().unwrap().to_str().unwrap(), "Captain");
+            assert_eq!(
+                stored.get_authority().unwrap(),
+                new_authority.pubkey().as_ref()
+            );
+        } else {
+            assert_eq!(borsh_person(&account).3, new_authority.pubkey());
+        }
+
+        // The old authority is locked out, the new one closes the account.
+        demo.send_to_person(funding, &funding.pubkey(), close(bump), false)
+            .await
+            .assert_error(DemoError::InvalidAuthority);
+        demo.send_to_person(&new_authority, &funding.pubkey(), close(bump), false)
+            .await
+            .assert_ok();
+        assert!(demo.account(&person).await.is_none());
+    }
+}
+
+#[tokio::test]
+async fn set_authority_rejects_a_malformed_key() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let (_, bump) = demo.person_address(&funding.pubkey());
+
+    let data = instruction_data(|instruction| {
+        let mut params = instruction.init_set_authority();
+        params.set_bump(bump);
+        params.set_new_authority(&[1; 31]);
+    });
+    demo.send_to_person(&funding, &funding.pubkey(), data, true)
+        .await
+        .assert_error(ProgramError::InvalidInstructionData);
+}
+
+#[tokio::test]
+async fn convert_round_trips_between_formats() {
+    let mut demo = Demo::start().await;
+    let (funding, other) = (demo.wallet().await, demo.wallet().await);
+    demo.initialize_borsh(&funding).await.assert_ok();
+    let (person, bump) = demo.person_address(&funding.pubkey());
+    let original = demo.account(&person).await.unwrap();
+
+    let data = convert_borsh_to_capnp(bump, true);
+    demo.send_with_descriptor(&other, &funding.pubkey(), DataFormat::Capnp, data.clone())
+        .await
+        .assert_error(DemoError::InvalidAuthority);
+    demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_ok();
+
+    let builder = person_builder("John", "Borsh", Some(&funding.pubkey()));
+    let account = demo.account(&person).await.unwrap();
+    assert_eq!(
+        account.data,
+        capnp_account_data(
+            &builder,
+            PERSON_V1,
+            MessageEncoding::Packed,
+            PERSON_SIZE_POLICY
+        )
+    );
+    // Converting created the capnp descriptor.
+    let descriptor = demo.descriptor_address(DataFormat::Capnp);
+    let descriptor = demo.account(&descriptor).await.unwrap();
+    let payload = assert_descriptor(&descriptor, &demo.program_id, SchemaEncoding::CapnpBinary);
+    assert_eq!(payload, PERSON_SCHEMA_REQUESTS[1]);
+
+    demo.send_with_descriptor(
+        &funding,
+        &funding.pubkey(),
+        DataFormat::Borsh,
+        convert_capnp_to_borsh(bump),
+    )
+    .await
+    .assert_ok();
+    let account = demo.account(&person).await.unwrap();
+    assert_eq!(account.data, original.data);
+    assert_eq!(account.lamports, original.lamports);
+}
+
+#[tokio::test]
+async fn v2_person_does_not_convert_to_borsh() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let (_, bump) = demo.person_address(&funding.pubkey());
+    let data = migrate_person(bump, "captain@proto.dev", 1987, "Wordsworth");
+    demo.send_with_descriptor(&funding, &funding.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_ok();
+
+    demo.send_with_descriptor(
+        &funding,
+        &funding.pubkey(),
+        DataFormat::Borsh,
+        convert_capnp_to_borsh(bump),
+    )
+    .await
+    .assert_error(DemoError::SchemaVersion);
+}
+
+#[tokio::test]
+async fn schema_upload_replaces_the_descriptor_schema() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let descriptor = demo.descriptor_address(DataFormat::Capnp);
+    let upgrade_authority = upgrade_authority();
+    let schema = big_schema();
+    let buffer = demo.create_schema_buffer(schema.len()).await;
+    let buffer = buffer.pubkey();
+
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        begin_schema_upload(SchemaEncoding::CapnpBinary),
+    )
+    .await
+    .assert_ok();
+    let header = SchemaBufferHeader::unpack(&demo.account(&buffer).await.unwrap().data).unwrap();
+    assert_eq!(
+        header,
+        SchemaBufferHeader {
+            encoding: SchemaEncoding::CapnpBinary,
+            descriptor,
+            authority: upgrade_authority.pubkey(),
+        }
+    );
+
+    demo.write_schema(&upgrade_authority, &buffer, &schema, 900)
+        .await;
+    // Until finalize, the descriptor only grows, and still holds the schema it was created with.
+    let grown = demo.account(&descriptor).await.unwrap();
+    assert_eq!(grown.data.len(), DescriptorHeader::LEN + schema.len());
+    let (_, payload) = read_descriptor(&grown.data).unwrap();
+    assert_eq!(payload, PERSON_SCHEMA_REQUESTS[1]);
+
+    let buffer_rent = demo.account(&buffer).await.unwrap().lamports;
+    let balance = demo
+        .account(&upgrade_authority.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        finalize_schema(&hash(&schema).to_bytes()),
+    )
+    .await
+    .assert_ok();
+
+    let account = demo.account(&descriptor).await.unwrap();
+    assert_eq!(
+        assert_descriptor(&account, &demo.program_id, SchemaEncoding::CapnpBinary),
+        schema
+    );
+    assert!(demo.account(&buffer).await.is_none());
+    assert_eq!(
+        demo.account(&upgrade_authority.pubkey())
+            .await
+            .unwrap()
+            .lamports,
+        balance + buffer_rent
+    );
+
+    // The uploaded schema reads every Person, so Persons are still created and migrated under it,
+    // before the program is rebuilt with it.
+    let other = demo.wallet().await;
+    demo.initialize_capnp(&other, false).await.assert_ok();
+    let bump = demo.person_address(&other.pubkey()).1;
+    let data = migrate_person(bump, "captain@proto.dev", 1987, "Wordsworth");
+    demo.send_with_descriptor(&other, &other.pubkey(), DataFormat::Capnp, data)
+        .await
+        .assert_ok();
+    let account = demo.account(&descriptor).await.unwrap();
+    assert_eq!(
+        assert_descriptor(&account, &demo.program_id, SchemaEncoding::CapnpBinary),
+        schema
+    );
+}
+
+#[tokio::test]
+async fn schema_upload_rejects_incompatible_schemas() {
+    let mut demo = Demo::start().await;
+    let (capnp, borsh) = (demo.wallet().await, demo.wallet().await);
+    demo.initialize_capnp(&capnp, false).await.assert_ok();
+    demo.initialize_borsh(&borsh).await.assert_ok();
+    let upgrade_authority = upgrade_authority();
+
+    let uploads: [(DataFormat, SchemaEncoding, &[u8]); 3] = [
+        // Compiled person.capnp lacks the fields v2 Persons hold.
+        (
+            DataFormat::Capnp,
+            SchemaEncoding::CapnpBinary,
+            PERSON_SCHEMA_REQUESTS[0],
+        ),
+        // Schemas other than compiled capnp have to be ones the program was built with.
+        (
+            DataFormat::Capnp,
+            SchemaEncoding::CapnpText,
+            b"@0xd7f46c866337c03c;\nstruct Person {}\n",
+        ),
+        (DataFormat::Borsh, SchemaEncoding::Borsh, b"{}"),
+    ];
+    for (format, encoding, schema) in uploads {
+        let descriptor = demo.descriptor_address(format);
+        let stored = demo.account(&descriptor).await.unwrap().data;
+        let buffer = demo.create_schema_buffer(schema.len()).await;
+        let buffer = buffer.pubkey();
+        demo.send_to_schema_buffer(
+            &upgrade_authority,
+            &buffer,
+            format,
+            begin_schema_upload(encoding),
+        )
+        .await
+        .assert_ok();
+        for (index, chunk) in schema.chunks(600).enumerate() {
+            let data = write_schema_chunk(index as u32 * 600, chunk);
+            demo.send_to_schema_buffer(&upgrade_authority, &buffer, format, data)
+                .await
+                .assert_ok();
+        }
+
+        demo.send_to_schema_buffer(
+            &upgrade_authority,
+            &buffer,
+            format,
+            finalize_schema(&hash(schema).to_bytes()),
+        )
+        .await
+        .assert_error(DemoError::IncompatibleSchema);
+        assert_eq!(demo.account(&descriptor).await.unwrap().data, stored);
+        assert!(demo.account(&buffer).await.is_some());
+    }
+}
+
+#[tokio::test]
+async fn cancelled_schema_upload_shrinks_the_descriptor_back() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let descriptor = demo.descriptor_address(DataFormat::Capnp);
+    let created = demo.account(&descriptor).await.unwrap();
+    let upgrade_authority = upgrade_authority();
+    let schema = big_schema();
+    let buffer = demo.create_schema_buffer(schema.len()).await;
+    let buffer = buffer.pubkey();
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        begin_schema_upload(SchemaEncoding::CapnpBinary),
+    )
+    .await
+    .assert_ok();
+    demo.write_schema(&upgrade_authority, &buffer, &schema, 900)
+        .await;
+    let grown = demo.account(&descriptor).await.unwrap();
+    assert_eq!(grown.data.len(), DescriptorHeader::LEN + schema.len());
+
+    demo.send_to_schema_buffer(&funding, &buffer, DataFormat::Capnp, cancel_schema_upload())
+        .await
+        .assert_error(ProgramError::IllegalOwner);
+
+    let buffer_rent = demo.account(&buffer).await.unwrap().lamports;
+    let balance = demo
+        .account(&upgrade_authority.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        cancel_schema_upload(),
+    )
+    .await
+    .assert_ok();
+
+    // The descriptor is back to the schema it held, and the rent of what the writes added is
+    // refunded along with the buffer's.
+    let account = demo.account(&descriptor).await.unwrap();
+    assert_eq!(account.data, created.data);
+    assert_eq!(account.lamports, created.lamports);
+    assert!(demo.account(&buffer).await.is_none());
+    assert_eq!(
+        demo.account(&upgrade_authority.pubkey())
+            .await
+            .unwrap()
+            .lamports,
+        balance + buffer_rent + grown.lamports - created.lamports
+    );
+}
+
+#[tokio::test]
+async fn schema_upload_needs_the_upgrade_authority() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let upgrade_authority = upgrade_authority();
+    let buffer = demo.create_schema_buffer(1000).await;
+    let buffer = buffer.pubkey();
+    let begin = begin_schema_upload(SchemaEncoding::CapnpText);
+
+    demo.send_to_schema_buffer(&funding, &buffer, DataFormat::Capnp, begin.clone())
+        .await
+        .assert_error(ProgramError::IllegalOwner);
+    demo.send_to_schema_buffer(&upgrade_authority, &buffer, DataFormat::Capnp, begin)
+        .await
+        .assert_ok();
+    // A buffer is begun once, so one upload can't take over another.
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        begin_schema_upload(SchemaEncoding::CapnpBinary),
+    )
+    .await
+    .assert_error(ProgramError::AccountAlreadyInitialized);
+
+    demo.send_to_schema_buffer(
+        &funding,
+        &buffer,
+        DataFormat::Capnp,
+        write_schema_chunk(0, b"@0x"),
+    )
+    .await
+    .assert_error(ProgramError::IllegalOwner);
+    demo.send_to_schema_buffer(
+        &funding,
+        &buffer,
+        DataFormat::Capnp,
+        finalize_schema(&[0; 32]),
+    )
+    .await
+    .assert_error(ProgramError::IllegalOwner);
+}
+
+#[tokio::test]
+async fn schema_upload_matches_the_descriptor_format() {
+    let mut demo = Demo::start().await;
+    let (capnp, borsh) = (demo.wallet().await, demo.wallet().await);
+    demo.initialize_capnp(&capnp, false).await.assert_ok();
+    demo.initialize_borsh(&borsh).await.assert_ok();
+    let upgrade_authority = upgrade_authority();
+    let buffer = demo.create_schema_buffer(1000).await;
+    let buffer = buffer.pubkey();
+
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        begin_schema_upload(SchemaEncoding::Borsh),
+    )
+    .await
+    .assert_error(ProgramError::InvalidInstructionData);
+    for encoding in [SchemaEncoding::CapnpText, SchemaEncoding::CapnpBinary] {
+        demo.send_to_schema_buffer(
+            &upgrade_authority,
+            &buffer,
+            DataFormat::Borsh,
+            begin_schema_upload(encoding),
+        )
+        .await
+        .assert_error(ProgramError::InvalidInstructionData);
+    }
+
+    // Nothing was written, so the buffer can still be begun for the right encoding.
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Borsh,
+        begin_schema_upload(SchemaEncoding::Borsh),
+    )
+    .await
+    .assert_ok();
+}
+
+#[tokio::test]
+async fn schema_upload_rejects_bad_chunks_and_hashes() {
+    let mut demo = Demo::start().await;
+    let funding = demo.wallet().await;
+    demo.initialize_capnp(&funding, false).await.assert_ok();
+    let descriptor = demo.descriptor_address(DataFormat::Capnp);
+    let upgrade_authority = upgrade_authority();
+    let schema = PERSON_SCHEMAS[1].as_bytes();
+    let buffer = demo.create_schema_buffer(schema.len()).await;
+    let buffer = buffer.pubkey();
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        begin_schema_upload(SchemaEncoding::CapnpText),
+    )
+    .await
+    .assert_ok();
+
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        write_schema_chunk(schema.len() as u32 - 2, b"end"),
+    )
+    .await
+    .assert_error(DemoError::SizeOverflow);
+    // The buffer was begun for the capnp descriptor.
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Borsh,
+        write_schema_chunk(0, b"@0x"),
+    )
+    .await
+    .assert_error(ProgramError::InvalidArgument);
+
+    demo.write_schema(&upgrade_authority, &buffer, schema, 900)
+        .await;
+    let mut wrong_hash = hash(schema).to_bytes();
+    wrong_hash[0] ^= 1;
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        finalize_schema(&wrong_hash),
+    )
+    .await
+    .assert_error(DemoError::SchemaHash);
+    demo.send_to_schema_buffer(
+        &upgrade_authority,
+        &buffer,
+        DataFormat::Capnp,
+        finalize_schema(&wrong_hash[..31]),
+    )
+    .await
+    .assert_error(ProgramError::InvalidInstructionData);
+
+    // Nothing reached the descriptor.
+    let (header, payload) =
+        read_descriptor(&demo.account(&descriptor).await.unwrap().data).unwrap();
+    assert_eq!(header.encoding, SchemaEncoding::CapnpBinary);
+    assert_eq!(payload, PERSON_SCHEMA_REQUESTS[1]);
+    assert!(demo.account(&buffer).await.is_some());
+}
@@ -1,87 +1,175 @@
-mod person_capnp;
-mod schema_capnp;
-
 #[cfg(test)]
 mod tests {
     use capnp::message::ReaderOptions;
     use capnp::serialize;
-    use capnpc::CompilerCommand;
-    use solana_program::msg;
+    use capnp_models::{schema_capnp, PERSON_SCHEMA_REQUESTS};
+    use capnpc::codegen::CodeGenerationCommand;
+
+    use solana_capnp_demo::account::{find_account_descriptor, CAPNP_HEADER_LEN, PERSON_TYPE_NAME};
+    use solana_capnp_demo::descriptor::{
+        find_descriptor_address, read_descriptor, DataFormat, SchemaEncoding,
+    };
+    use solana_capnp_demo::instruction_capnp::demo_instruction;
+    use solana_capnp_demo::{instruction, person_v2_capnp};
+    use solana_program::instruction::{AccountMeta, Instruction};
+    use solana_program::pubkey::Pubkey;
+    use solana_program::system_program;
+    use solana_program_test::{processor, ProgramTest};
+    use solana_sdk::account::Account;
+    use solana_sdk::signature::{Keypair, Signer};
+    use solana_sdk::transaction::Transaction;
+
+    /// Initializes a capnp Person in `ProgramTest` and returns the Person account's data and the
+    /// schema stored in its descriptor.
+    async fn person_and_schema() -> (Vec<u8>, Vec<u8>) {
+        let program_id = Pubkey::new_unique();
+        let funding = Keypair::new();
+        let mut program_test =
+            ProgramTest::new("solana_capnp_demo", program_id, processor!(instruction));
+        program_test.add_account(
+            funding.pubkey(),
+            Account::new(1_000_000_000, 0, &system_program::id()),
+        );
+        let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let (person, bump) = Pubkey::find_program_address(
+            &[b"customaddress", funding.pubkey().as_ref()],
+            &program_id,
+        );
+        let (descriptor, _) =
+            find_descriptor_address(&program_id, DataFormat::Capnp, PERSON_TYPE_NAME);
 
-    use bryte_descriptor_client::{get_account_schema, get_raw_account_data};
+        let mut message = capnp::message::Builder::new_default();
+        let mut params = message
+            .init_root::<demo_instruction::Builder>()
+            .init_initialize_capnp();
+        params.set_bump(bump);
+        let mut new_person = params.init_person();
+        new_person.set_firstname("Captain".into());
+        new_person.set_lastname("Proto".into());
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &serialize::write_message_to_words(&message),
+            vec![
+                AccountMeta::new(funding.pubkey(), true),
+                AccountMeta::new(person, false),
+                AccountMeta::new(descriptor, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        );
+        banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&payer.pubkey()),
+                &[&payer, &funding],
+                recent_blockhash,
+            ))
+            .await
+            .unwrap();
 
-    use crate::person_capnp;
-    use crate::schema_capnp;
+        // Clients only need the Person account: its discriminator tells which descriptor holds
+        // the schema.
+        let person = banks_client.get_account(person).await.unwrap().unwrap();
+        let (descriptor, _) = find_account_descriptor(&program_id, &person.data).unwrap();
+        let descriptor = banks_client.get_account(descriptor).await.unwrap().unwrap();
+        let (header, schema) = read_descriptor(&descriptor.data).unwrap();
+        assert_eq!(header.encoding, SchemaEncoding::CapnpBinary);
+        (person.data, schema.to_vec())
+    }
 
     #[tokio::test]
     pub async fn capnp_binary_schema_test() {
-        // TODO update the account pubkey with the key that was generated during the capnp_demo_client run.
-        // TODO update the program ID with the ID generated during deploy
-        let (discriminator, data) = get_raw_account_data(
-            "ATpykMvDAu4eG5YLckTLYYqzSKgmn7HBdfPvLALGYaQS".to_string(),
-            "EmcSKVqz9rAwFPWDJ6YxvBLNaFjESNMrJkaREVs7MC72".to_string(),
-            "http://localhost:8899".to_string(),
-        )
-        .await
-        .unwrap();
-        let schema = get_account_schema(
-            "ATpykMvDAu4eG5YLckTLYYqzSKgmn7HBdfPvLALGYaQS".to_string(),
-            "EmcSKVqz9rAwFPWDJ6YxvBLNaFjESNMrJkaREVs7MC72".to_string(),
-            true,
-            "http://localhost:8899".to_string(),
-        )
-        .await
-        .unwrap();
-        std::fs::write("./tests/person.bin", schema).unwrap();
-        let result = std::fs::read("./tests/person.bin").unwrap();
-        let reader = serialize::read_message(result.as_slice(), ReaderOptions::new()).unwrap();
+        let (_, schema) = person_and_schema().await;
 
-        let schema = reader
+        // The descriptor holds the binary CodeGeneratorRequest the capnp compiler hands its
+        // plugins, which reads without the compiler.
+        let reader = serialize::read_message(schema.as_slice(), ReaderOptions::new()).unwrap();
+        let request = reader
             .get_root::<schema_capnp::code_generator_request::Reader>()
             .unwrap();
-        println!("{:?}", schema);
+        let requested_file = request.get_requested_files().unwrap().get(0);
+        assert_eq!(
+            requested_file.get_filename().unwrap().to_str(),
+            Ok("person_v2.capnp")
+        );
+
+        let person = request
+            .get_nodes()
+            .unwrap()
+            .iter()
+            .find(|node| {
+                let display_name = node.get_display_name().unwrap().to_str().unwrap();
+                display_name.ends_with("person_v2.capnp:Person")
+            })
+            .expect("Person node");
+        let fields = match person.which().unwrap() {
+            schema_capnp::node::Struct(person) => person.get_fields().unwrap(),
+            _ => panic!("Person is not a struct"),
+        };
+        let names: Vec<String> = fields
+            .iter()
+            .map(|field| field.get_name().unwrap().to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "firstname",
+                "lastname",
+                "email",
+                "birthYear",
+                "address",
+                "authority"
+            ]
+        );
     }
 
     #[tokio::test]
     pub async fn capnp_binary_schema_generation_test() {
-        // TODO update the account pubkey with the key that was generated during the capnp_demo_client run.
-        // TODO update the program ID with the ID generated during deploy
-        // Get the discriminator and account data from your primary account.
-        let (discriminator, data) = get_raw_account_data(
-            "ATpykMvDAu4eG5YLckTLYYqzSKgmn7HBdfPvLALGYaQS".to_string(),
-            "EmcSKVqz9rAwFPWDJ6YxvBLNaFjESNMrJkaREVs7MC72".to_string(),
-            "http://localhost:8899".to_string(),
-        )
-        .await
-        .unwrap();
+        let (data, schema) = person_and_schema().await;
 
-        // Get the schema for your primary account. Pass the primary account pubkey and the client library will look up the account descriptor and retrieve the schema.
-        let schema = get_account_schema(
-            "ATpykMvDAu4eG5YLckTLYYqzSKgmn7HBdfPvLALGYaQS".to_string(),
-            "EmcSKVqz9rAwFPWDJ6YxvBLNaFjESNMrJkaREVs7MC72".to_string(),
-            true,
-            "http://localhost:8899".to_string(),
-        )
-        .await
-        .unwrap();
-        // Save the schema to disk
-        std::fs::write("./tests/person.capnp", schema).unwrap();
+        // The descriptor holds the nodes of the published person_v2.capnp, so code generated
+        // from it is the code this crate reads Persons with.
+        let nodes = |request: &[u8]| {
+            let message = serialize::read_message(request, ReaderOptions::new()).unwrap();
+            let request = message
+                .get_root::<schema_capnp::code_generator_request::Reader>()
+                .unwrap();
+            request
+                .get_nodes()
+                .unwrap()
+                .iter()
+                .map(|node| {
+                    let name = node.get_display_name().unwrap().to_str().unwrap();
+                    (node.get_id(), name.to_string())
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(nodes(schema.as_slice()), nodes(PERSON_SCHEMA_REQUESTS[1]));
 
-        // Generate Rust code for the schema
-        CompilerCommand::new()
-            .file("./tests/person.capnp")
-            .output_path("./")
-            .run()
-            .expect("compiling schema");
+        // Generate Rust code for the schema, straight from the descriptor. The file is named
+        // person_v2.capnp in the request, so this writes person_v2_capnp.rs.
+        let dir = tempfile::tempdir().unwrap();
+        CodeGenerationCommand::new()
+            .output_directory(dir.path())
+            .run(schema.as_slice())
+            .expect("generating code");
+        let generated = std::fs::read_to_string(dir.path().join("person_v2_capnp.rs")).unwrap();
+        assert!(generated.contains("pub mod person {"));
 
-        // Utilize the generated code to deserialize the account data.
+        // Deserialize the account data with the code generated for person_v2.capnp. The message
+        // starts after the account header. The Person is v1, so the fields v2 adds read as defaults.
         let reader = serialize::read_message_from_flat_slice_no_alloc(
-            &mut data.as_slice(),
+            &mut &data[CAPNP_HEADER_LEN..],
             ReaderOptions::new(),
         )
         .unwrap();
 
-        let person = reader.get_root::<person_capnp::person::Reader>().unwrap();
-        msg!("{:?} {:?}", person.get_firstname(), person.get_lastname());
+        let person = reader
+            .get_root::<person_v2_capnp::person::Reader>()
+            .unwrap();
+        assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Captain");
+        assert_eq!(person.get_lastname().unwrap().to_str().unwrap(), "Proto");
+        assert!(!person.has_email());
+        assert_eq!(person.get_birth_year(), 0);
     }
 }
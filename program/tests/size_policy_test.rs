@@ -0,0 +1,49 @@
+use solana_capnp_demo::account::CAPNP_HEADER_LEN;
+use solana_capnp_demo::{SizePolicy, PERSON_SIZE_POLICY};
+
+#[test]
+fn exact_adds_only_the_header() {
+    for message_len in [0, 1, 57, 64, 1000] {
+        assert_eq!(
+            SizePolicy::Exact.account_size_for_len(message_len),
+            CAPNP_HEADER_LEN + message_len
+        );
+    }
+    assert_eq!(SizePolicy::Exact.account_size(3), CAPNP_HEADER_LEN + 24);
+}
+
+#[test]
+fn round_up_rounds_header_and_message_to_the_multiple() {
+    let policy = SizePolicy::RoundUp(64);
+    assert_eq!(policy.account_size_for_len(0), 64);
+    assert_eq!(policy.account_size_for_len(1), 64);
+    assert_eq!(policy.account_size_for_len(64 - CAPNP_HEADER_LEN), 64);
+    assert_eq!(policy.account_size_for_len(64 - CAPNP_HEADER_LEN + 1), 128);
+    assert_eq!(policy.account_size_for_len(200), 256);
+    assert_eq!(policy.account_size(6), 64);
+    assert_eq!(policy.account_size(7), 128);
+}
+
+#[test]
+fn round_up_to_one_or_zero_is_exact() {
+    for multiple in [0, 1] {
+        for message_len in [0, 5, 57] {
+            assert_eq!(
+                SizePolicy::RoundUp(multiple).account_size_for_len(message_len),
+                SizePolicy::Exact.account_size_for_len(message_len)
+            );
+        }
+    }
+}
+
+#[test]
+fn person_accounts_round_up() {
+    assert_eq!(PERSON_SIZE_POLICY, SizePolicy::RoundUp(64));
+    // Packed messages aren't whole words, their accounts still are whole multiples.
+    for message_len in 1..300 {
+        let size = PERSON_SIZE_POLICY.account_size_for_len(message_len);
+        assert_eq!(size % 64, 0);
+        assert!(size >= CAPNP_HEADER_LEN + message_len);
+        assert!(size < CAPNP_HEADER_LEN + message_len + 64);
+    }
+}
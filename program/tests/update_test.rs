@@ -0,0 +1,225 @@
+mod common;
+
+use capnp::message::{Builder, HeapAllocator};
+use common::{capnp_account_data, capnp_person_data, with_accounts};
+use solana_capnp_demo::account::{MessageEncoding, PERSON_V1, PERSON_V2};
+use solana_capnp_demo::error::DemoError;
+use solana_capnp_demo::person::{PersonAccount, PersonBuilder};
+use solana_capnp_demo::{
+    person_capnp, person_v2_capnp, update_capnp, MAX_NAME_LEN, PERSON_SIZE_POLICY,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// The Person an `UpdateCapnp` instruction carries.
+fn names(firstname: &str, lastname: &str) -> Builder<HeapAllocator> {
+    let mut message = Builder::new_default();
+    let mut person = message.init_root::<person_capnp::person::Builder>();
+    person.set_firstname(firstname.into());
+    person.set_lastname(lastname.into());
+    message
+}
+
+/// Sends `UpdateCapnp` with `firstname` and `lastname`, signed by `signer` if `is_signer`, to a
+/// Person account holding `data`.
+fn update(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    is_signer: bool,
+    data: &mut [u8],
+    firstname: &str,
+    lastname: &str,
+) -> Result<(), ProgramError> {
+    let message = names(firstname, lastname);
+    let person = message
+        .get_root_as_reader::<person_capnp::person::Reader>()
+        .unwrap();
+    with_accounts(program_id, signer, is_signer, data, false, |accounts| {
+        update_capnp(program_id, accounts, 0, person)
+    })
+}
+
+fn stored_names(program_id: &Pubkey, data: &mut [u8]) -> (String, String) {
+    common::with_account(program_id, data, |account| {
+        let person_account = PersonAccount::load(program_id, account).unwrap();
+        let message = person_account.message().unwrap();
+        let person = message.root::<person_capnp::person::Owned>().unwrap();
+        (
+            person
+                .get_firstname()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string(),
+            person.get_lastname().unwrap().to_str().unwrap().to_string(),
+        )
+    })
+}
+
+#[test]
+fn authority_updates_names() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    for encoding in [MessageEncoding::Unpacked, MessageEncoding::Packed] {
+        let mut data = capnp_person_data(Some(&authority), PERSON_V1, encoding, PERSON_SIZE_POLICY);
+        // Names as long as Captain Proto keep the account's size: resizing needs the Rent
+        // sysvar, which only the runtime has. program_test.rs covers updates that resize.
+        update(&program_id, &authority, true, &mut data, "Jackson", "Other").unwrap();
+
+        assert_eq!(
+            stored_names(&program_id, &mut data),
+            ("Jackson".to_string(), "Other".to_string())
+        );
+        common::with_account(&program_id, &mut data, |account| {
+            let person_account = PersonAccount::load(&program_id, account).unwrap();
+            assert_eq!(person_account.version(), PERSON_V1);
+            assert_eq!(person_account.encoding(), encoding);
+            assert_eq!(person_account.authority(), Ok(Some(authority)));
+        });
+    }
+}
+
+#[test]
+fn update_keeps_what_v2_adds() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut message = Builder::new_default();
+    {
+        let mut person = message.init_root::<person_v2_capnp::person::Builder>();
+        person.set_firstname("Captain".into());
+        person.set_lastname("Proto".into());
+        person.set_email("captain@proto.dev".into());
+        person.set_birth_year(1987);
+        person.init_address().set_city("Wordsworth".into());
+    }
+    let mut builder = PersonBuilder::from_message(PERSON_V2, message);
+    builder.set_authority(&authority).unwrap();
+    let mut data = capnp_account_data(
+        &builder,
+        PERSON_V2,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+
+    update(&program_id, &authority, true, &mut data, "Jackson", "Other").unwrap();
+
+    common::with_account(&program_id, &mut data, |account| {
+        let person_account = PersonAccount::load(&program_id, account).unwrap();
+        assert_eq!(person_account.version(), PERSON_V2);
+        let message = person_account.message().unwrap();
+        let person = message.root::<person_v2_capnp::person::Owned>().unwrap();
+        assert_eq!(person.get_firstname().unwrap().to_str().unwrap(), "Jackson");
+        assert_eq!(
+            person.get_email().unwrap().to_str().unwrap(),
+            "captain@proto.dev"
+        );
+        assert_eq!(person.get_birth_year(), 1987);
+        assert_eq!(
+            person
+                .get_address()
+                .unwrap()
+                .get_city()
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "Wordsworth"
+        );
+    });
+}
+
+#[test]
+fn rejects_empty_and_long_names() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+    let long_name = "x".repeat(MAX_NAME_LEN + 1);
+
+    for (firstname, lastname) in [
+        ("", "Other"),
+        ("Jackson", ""),
+        ("Jackson", long_name.as_str()),
+    ] {
+        assert_eq!(
+            update(
+                &program_id,
+                &authority,
+                true,
+                &mut data,
+                firstname,
+                lastname
+            ),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+    assert_eq!(
+        stored_names(&program_id, &mut data),
+        ("Captain".to_string(), "Proto".to_string())
+    );
+}
+
+#[test]
+fn only_the_authority_updates() {
+    let program_id = Pubkey::new_unique();
+    let (authority, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = capnp_person_data(
+        Some(&authority),
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+
+    assert_eq!(
+        update(&program_id, &other, true, &mut data, "Jackson", "Other"),
+        Err(DemoError::InvalidAuthority.into())
+    );
+    assert_eq!(
+        update(
+            &program_id,
+            &authority,
+            false,
+            &mut data,
+            "Jackson",
+            "Other"
+        ),
+        Err(ProgramError::MissingRequiredSignature)
+    );
+    assert_eq!(
+        stored_names(&program_id, &mut data),
+        ("Captain".to_string(), "Proto".to_string())
+    );
+}
+
+#[test]
+fn person_without_authority_needs_its_funding_account() {
+    let program_id = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let mut data = capnp_person_data(
+        None,
+        PERSON_V1,
+        MessageEncoding::Unpacked,
+        PERSON_SIZE_POLICY,
+    );
+
+    // The Person account isn't the PDA of `signer`.
+    assert_eq!(
+        update(&program_id, &signer, true, &mut data, "Jackson", "Other"),
+        Err(DemoError::InvalidAuthority.into())
+    );
+}
+
+#[test]
+fn rejects_borsh_person() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut data = common::borsh_person_data(&authority);
+
+    assert_eq!(
+        update(&program_id, &authority, true, &mut data, "Jackson", "Other"),
+        Err(DemoError::WrongDiscriminator.into())
+    );
+}